@@ -0,0 +1,821 @@
+//==============================================================================
+//! Derive macro for the CroftSoft Commander library
+//!
+//! # Usage
+//! - Add `#[derive(Commander)]` to a struct with named fields
+//! - Each field becomes an option with a long name made from the field name
+//! - The first paragraph of the field doc comment becomes the brief
+//!   description shown for -h and the other paragraphs become the long
+//!   description shown for -\-help
+//! - The field type selects the value usage and the typed conversion
+//!   - bool: a flag without a value which is true if present
+//!   - `Option<bool>`: an optional true/false value
+//!   - `Option<T>`: a required value converted using FromStr if present
+//!   - `Vec<T>`: a required value for each of the repeated options
+//!   - T: a required option with a required value converted using FromStr
+//! - Field attributes:
+//!   `#[commander(deprecated, display_order = 1, heading = "Input", hidden,
+//!   id = "x", long = "x", replaced_by = "--y", short = 'x', value_name = "X")]`
+//! - A deprecated option or one with a replacement is still parsed but adds a
+//!   warning to the parse results
+//! - The value name shown for -\-help defaults to BOOL for `Option<bool>`
+//! - Struct attributes:
+//!   `#[commander(app_name = "", bug_report = "", command_name = "",
+//!   contact = "", copyright = "", homepage = "", license = "",
+//!   no_version_options, version = "")]`
+//! - The version defaults to the crate version from CARGO_PKG_VERSION
+//! - Unless no_version_options is set, -V, -\-version, and -\-verbose are
+//!   added to the options and to the help, and the parse function prints the
+//!   version and exits if -V or -\-version is present
+//! - A field named version or verbose fails the build unless
+//!   no_version_options is set, and a field using -V leaves -\-version
+//!   without a short name
+//! - The first paragraph of the struct doc comment becomes the about text and
+//!   the whole doc comment becomes the long about text shown for -\-help
+//! - Generates the APP_INFO, BUILD_INFO, HELP_INFO, and OPTION_CONFIGS
+//!   constants
+//! - BUILD_INFO has the crate version and, if set when building such as by a
+//!   build script, the TARGET environment variable
+//! - Generates a parse function which returns the parse results on errors,
+//!   made from the make_parse_results and from_parse_results functions which
+//!   do not print the version or exit
+//! - A bool field with the id "help" is the help flag: when it is present, the
+//!   parse function ignores the errors so the caller can print the help, and
+//!   returns the parse results if a required option is missing so the caller
+//!   can check for the help option using ParseResults::is_present
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use ::proc_macro::TokenStream;
+use ::proc_macro2::TokenStream as TokenStream2;
+use ::quote::{format_ident, quote};
+use ::syn::{
+  Attribute, Data, DeriveInput, Expr, ExprLit, Field, Fields, GenericArgument,
+  Ident, Lit, LitChar, LitInt, LitStr, Meta, PathArguments, Type,
+  parse_macro_input,
+};
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// How a field is parsed, selected by the field type
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FieldKind {
+  /// bool: a flag without a value
+  Flag,
+  /// `Option<T>`: a required value if the option is present
+  Optional,
+  /// `Option<bool>`: an optional true/false value
+  OptionalBool,
+  /// `Vec<T>`: a required value for each occurrence
+  Repeated,
+  /// T: a required option with a required value
+  Required,
+}
+
+//------------------------------------------------------------------------------
+/// The option configuration for a field
+//------------------------------------------------------------------------------
+struct FieldConfig {
+  brief_description: Option<String>,
+  /// Whether a full parse warns that the option is deprecated
+  deprecated: bool,
+  display_order: Option<usize>,
+  field_kind: FieldKind,
+  /// The heading of the section for -\-help
+  heading: Option<String>,
+  /// Whether the option is left out of -\-help
+  hidden: bool,
+  id: String,
+  ident: Ident,
+  /// The doc comment paragraphs after the first
+  long_description: Option<String>,
+  name_long: String,
+  name_short: Option<char>,
+  /// The option named in the deprecation warning to use instead
+  replacement: Option<String>,
+  /// The placeholder for the value shown for -\-help
+  value_name: Option<String>,
+  /// The type converted to, such as T in `Option<T>`
+  value_type: Type,
+}
+
+//------------------------------------------------------------------------------
+/// The application information for the struct
+//------------------------------------------------------------------------------
+#[derive(Default)]
+struct StructConfig {
+  about: Option<String>,
+  app_name: Option<String>,
+  bug_report: Option<String>,
+  command_name: Option<String>,
+  contact: Option<String>,
+  copyright: Option<String>,
+  homepage: Option<String>,
+  license: Option<String>,
+  long_about: Option<String>,
+  /// Leaves out the built-in -V, -\-version, and -\-verbose options
+  no_version_options: bool,
+  version: Option<String>,
+}
+
+//------------------------------------------------------------------------------
+/// Generates the option configurations, help information, and parse function
+//------------------------------------------------------------------------------
+#[proc_macro_derive(
+  Commander,
+  attributes(commander)
+)]
+pub fn derive_commander(input: TokenStream) -> TokenStream {
+  let derive_input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+  expand(&derive_input)
+    .unwrap_or_else(|error: ::syn::Error| error.to_compile_error())
+    .into()
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// Generates the implementation for the struct
+//------------------------------------------------------------------------------
+fn expand(derive_input: &DeriveInput) -> ::syn::Result<TokenStream2> {
+  let ident: &Ident = &derive_input.ident;
+
+  if !derive_input.generics.params.is_empty() {
+    return Err(::syn::Error::new_spanned(
+      &derive_input.generics,
+      "Commander cannot be derived for a generic struct",
+    ));
+  }
+
+  let Data::Struct(data_struct) = &derive_input.data else {
+    return Err(::syn::Error::new_spanned(
+      ident,
+      "Commander can only be derived for a struct",
+    ));
+  };
+
+  let Fields::Named(fields_named) = &data_struct.fields else {
+    return Err(::syn::Error::new_spanned(
+      ident,
+      "Commander can only be derived for a struct with named fields",
+    ));
+  };
+
+  let struct_config: StructConfig = parse_struct_config(&derive_input.attrs)?;
+
+  let field_configs: Vec<FieldConfig> = fields_named
+    .named
+    .iter()
+    .map(parse_field_config)
+    .collect::<::syn::Result<Vec<FieldConfig>>>()?;
+
+  let app_info: TokenStream2 = make_app_info(&struct_config);
+
+  let command_name: TokenStream2 =
+    quote_option_str(&struct_config.command_name);
+
+  let option_configs: Vec<TokenStream2> =
+    field_configs.iter().map(make_option_config).collect();
+
+  let version_options: bool = !struct_config.no_version_options;
+
+  if version_options {
+    check_version_names(&field_configs)?;
+  }
+
+  let parse_function: TokenStream2 =
+    make_parse_function(&field_configs, &struct_config);
+
+  Ok(quote! {
+    impl #ident {
+      pub const APP_INFO: ::commander::print::app_info::AppInfo<'static> =
+        #app_info;
+
+      pub const BUILD_INFO:
+        ::commander::print::build_info::BuildInfo<'static> =
+        ::commander::print::build_info::BuildInfo {
+          features: &[],
+          target: option_env!("TARGET"),
+          version: option_env!("CARGO_PKG_VERSION"),
+        };
+
+      pub const HELP_INFO: ::commander::print::help_info::HelpInfo<'static> =
+        ::commander::print::help_info::HelpInfo {
+          app_info: &Self::APP_INFO,
+          arg_options: Self::OPTION_CONFIGS,
+          command_name: #command_name,
+          parse_groups: &[],
+          positionals: &[],
+          sort_options: false,
+          subcommands: &[],
+          usage: None,
+          version_options: #version_options,
+        };
+
+      pub const OPTION_CONFIGS:
+        &'static [::commander::print::option_config::OptionConfig<'static>] =
+        &[#(#option_configs),*];
+
+      #parse_function
+    }
+
+    const _: () =
+      ::commander::print::option_config::OptionConfig::assert_valid_slice(
+        #ident::OPTION_CONFIGS,
+      );
+  })
+}
+
+//------------------------------------------------------------------------------
+/// Fails if a field uses the id or long name of the built-in version options
+//------------------------------------------------------------------------------
+fn check_version_names(field_configs: &[FieldConfig]) -> ::syn::Result<()> {
+  for field_config in field_configs {
+    for name in [
+      &field_config.id,
+      &field_config.name_long,
+    ] {
+      if name == "verbose" || name == "version" {
+        return Err(::syn::Error::new_spanned(
+          &field_config.ident,
+          format!(
+            "Option \"{name}\" clashes with the built-in version options, \
+             add #[commander(no_version_options)] to the struct"
+          ),
+        ));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+//------------------------------------------------------------------------------
+/// Whether the type is the plain name such as bool
+//------------------------------------------------------------------------------
+fn is_type_named(
+  field_type: &Type,
+  name: &str,
+) -> bool {
+  let Type::Path(type_path) = field_type else {
+    return false;
+  };
+
+  type_path.path.is_ident(name)
+}
+
+//------------------------------------------------------------------------------
+/// The AppInfo expression for the struct
+//------------------------------------------------------------------------------
+fn make_app_info(struct_config: &StructConfig) -> TokenStream2 {
+  let about: TokenStream2 = quote_option_str(&struct_config.about);
+
+  let contact: TokenStream2 = quote_option_str(&struct_config.contact);
+
+  let bug_report: TokenStream2 = quote_option_str(&struct_config.bug_report);
+
+  let copyright: TokenStream2 = quote_option_str(&struct_config.copyright);
+
+  let homepage: TokenStream2 = quote_option_str(&struct_config.homepage);
+
+  let license: TokenStream2 = quote_option_str(&struct_config.license);
+
+  let long_about: TokenStream2 = quote_option_str(&struct_config.long_about);
+
+  let name: TokenStream2 = quote_option_str(&struct_config.app_name);
+
+  let version: TokenStream2 = match &struct_config.version {
+    Some(version) => quote! { Some(#version) },
+    None => quote! { Some(env!("CARGO_PKG_VERSION")) },
+  };
+
+  quote! {
+    ::commander::print::app_info::AppInfo {
+      about: #about,
+      authors: &[],
+      bug_report: #bug_report,
+      contact: #contact,
+      copyright: #copyright,
+      homepage: #homepage,
+      license: #license,
+      long_about: #long_about,
+      name: #name,
+      version: #version,
+    }
+  }
+}
+
+//------------------------------------------------------------------------------
+/// The doc comment paragraphs, each with its lines joined with spaces
+///
+/// - Paragraphs are separated by blank doc comment lines
+//------------------------------------------------------------------------------
+fn make_doc_paragraphs(attrs: &[Attribute]) -> Vec<String> {
+  let mut paragraphs: Vec<String> = Vec::new();
+
+  let mut lines: Vec<String> = Vec::new();
+
+  for attr in attrs {
+    let Meta::NameValue(meta_name_value) = &attr.meta else {
+      continue;
+    };
+
+    if !meta_name_value.path.is_ident("doc") {
+      continue;
+    }
+
+    let Expr::Lit(ExprLit {
+      lit: Lit::Str(lit_str),
+      ..
+    }) = &meta_name_value.value
+    else {
+      continue;
+    };
+
+    let line: String = lit_str.value().trim().to_string();
+
+    if !line.is_empty() {
+      lines.push(line);
+    } else if !lines.is_empty() {
+      paragraphs.push(lines.join(" "));
+
+      lines.clear();
+    }
+  }
+
+  if !lines.is_empty() {
+    paragraphs.push(lines.join(" "));
+  }
+
+  paragraphs
+}
+
+//------------------------------------------------------------------------------
+/// The field kind and the value type for a field type
+//------------------------------------------------------------------------------
+fn make_field_kind(field_type: &Type) -> (FieldKind, Type) {
+  if is_type_named(field_type, "bool") {
+    return (FieldKind::Flag, field_type.clone());
+  }
+
+  if let Some(inner_type) = make_inner_type(field_type, "Option") {
+    if is_type_named(&inner_type, "bool") {
+      return (FieldKind::OptionalBool, inner_type);
+    }
+
+    return (FieldKind::Optional, inner_type);
+  }
+
+  if let Some(inner_type) = make_inner_type(field_type, "Vec") {
+    return (FieldKind::Repeated, inner_type);
+  }
+
+  (FieldKind::Required, field_type.clone())
+}
+
+//------------------------------------------------------------------------------
+/// The type argument T if the type is wrapper_name<T>
+//------------------------------------------------------------------------------
+fn make_inner_type(
+  field_type: &Type,
+  wrapper_name: &str,
+) -> Option<Type> {
+  let Type::Path(type_path) = field_type else {
+    return None;
+  };
+
+  let last_segment = type_path.path.segments.last()?;
+
+  if last_segment.ident != wrapper_name {
+    return None;
+  }
+
+  let PathArguments::AngleBracketed(arguments) = &last_segment.arguments else {
+    return None;
+  };
+
+  match arguments.args.first()? {
+    GenericArgument::Type(inner_type) => Some(inner_type.clone()),
+    _ => None,
+  }
+}
+
+//------------------------------------------------------------------------------
+/// The long name for a field name with underscores replaced by hyphens
+//------------------------------------------------------------------------------
+fn make_name_long(ident: &Ident) -> String {
+  ident.to_string().trim_start_matches("r#").replace('_', "-")
+}
+
+//------------------------------------------------------------------------------
+/// The OptionConfig builder expression for a field
+//------------------------------------------------------------------------------
+fn make_option_config(field_config: &FieldConfig) -> TokenStream2 {
+  let id: &str = &field_config.id;
+
+  let name_long: &str = &field_config.name_long;
+
+  let mut option_config: TokenStream2 = quote! {
+    ::commander::print::option_config::OptionConfig::long(#name_long).id(#id)
+  };
+
+  if let Some(name_short) = field_config.name_short {
+    option_config.extend(quote! { .short(#name_short) });
+  }
+
+  option_config.extend(match field_config.field_kind {
+    FieldKind::Flag => quote! {
+      .duplicate_policy(
+        ::commander::parse::duplicate_policy::DuplicatePolicy::LastWins
+      )
+    },
+    FieldKind::Optional => quote! {
+      .duplicate_policy(
+        ::commander::parse::duplicate_policy::DuplicatePolicy::LastWins
+      )
+      .required_value()
+    },
+    FieldKind::OptionalBool => quote! {
+      .duplicate_policy(
+        ::commander::parse::duplicate_policy::DuplicatePolicy::LastWins
+      )
+      .optional_value()
+    },
+    FieldKind::Repeated => quote! {
+      .duplicate_policy(
+        ::commander::parse::duplicate_policy::DuplicatePolicy::Accumulate
+      )
+      .required_value()
+    },
+    FieldKind::Required => quote! {
+      .duplicate_policy(
+        ::commander::parse::duplicate_policy::DuplicatePolicy::LastWins
+      )
+      .required_value()
+      .required()
+    },
+  });
+
+  if let Some(brief_description) = &field_config.brief_description {
+    option_config.extend(quote! { .help(#brief_description) });
+  }
+
+  if let Some(replacement) = &field_config.replacement {
+    option_config.extend(quote! { .replaced_by(#replacement) });
+  } else if field_config.deprecated {
+    option_config.extend(quote! { .deprecated() });
+  }
+
+  if let Some(display_order) = field_config.display_order {
+    option_config.extend(quote! { .display_order(#display_order) });
+  }
+
+  if let Some(heading) = &field_config.heading {
+    option_config.extend(quote! { .heading(#heading) });
+  }
+
+  if field_config.hidden {
+    option_config.extend(quote! { .hidden() });
+  }
+
+  if let Some(long_description) = &field_config.long_description {
+    option_config.extend(quote! { .long_help(#long_description) });
+  }
+
+  if let Some(value_name) = &field_config.value_name {
+    option_config.extend(quote! { .value_name(#value_name) });
+  }
+
+  option_config
+}
+
+//------------------------------------------------------------------------------
+/// The parse functions which parse the arguments and convert the parse
+/// results to the struct
+//------------------------------------------------------------------------------
+fn make_parse_function(
+  field_configs: &[FieldConfig],
+  struct_config: &StructConfig,
+) -> TokenStream2 {
+  let mut conversions: Vec<TokenStream2> = Vec::new();
+
+  let mut required_checks: Vec<TokenStream2> = Vec::new();
+
+  let mut field_values: Vec<TokenStream2> = Vec::new();
+
+  for field_config in field_configs {
+    let id: &str = &field_config.id;
+
+    let ident: &Ident = &field_config.ident;
+
+    let value_type: &Type = &field_config.value_type;
+
+    let variable: Ident = format_ident!("field_{}", ident);
+
+    conversions.push(match field_config.field_kind {
+      FieldKind::Flag => quote! {
+        let #variable: bool = parse_results.is_present(#id);
+      },
+      FieldKind::Optional | FieldKind::Required => quote! {
+        let #variable: Option<#value_type> =
+          parse_results.convert_last::<#value_type>(#id);
+      },
+      FieldKind::OptionalBool => quote! {
+        let #variable: Option<bool> = parse_results.convert_last_bool(#id);
+      },
+      FieldKind::Repeated => quote! {
+        let #variable: Vec<#value_type> =
+          parse_results.convert_all::<#value_type>(#id);
+      },
+    });
+
+    if field_config.field_kind == FieldKind::Required {
+      required_checks.push(quote! {
+        let Some(#variable) = #variable else {
+          return Err(parse_results);
+        };
+      });
+    }
+
+    field_values.push(quote! { #ident: #variable });
+  }
+
+  let help_check: TokenStream2 =
+    match field_configs.iter().find(|field_config: &&FieldConfig| {
+      field_config.field_kind == FieldKind::Flag && field_config.id == "help"
+    }) {
+      Some(help_field_config) => {
+        let variable: Ident =
+          format_ident!("field_{}", help_field_config.ident);
+
+        quote! { !#variable && }
+      },
+      None => TokenStream2::new(),
+    };
+
+  let (option_configs, version_check): (TokenStream2, TokenStream2) =
+    if struct_config.no_version_options {
+      (
+        quote! { Self::OPTION_CONFIGS.to_vec() },
+        TokenStream2::new(),
+      )
+    } else {
+      (
+        quote! {
+          ::commander::print::version_option::add_version_options(
+            Self::OPTION_CONFIGS,
+          )
+        },
+        quote! {
+          ::commander::print::version_option::version_and_exit(
+            &Self::APP_INFO,
+            &Self::BUILD_INFO,
+            &parse_results,
+          );
+        },
+      )
+    };
+
+  quote! {
+    //--------------------------------------------------------------------------
+    /// Converts the parse results into the struct
+    ///
+    /// - Returns the parse results if there are errors or unknown options
+    /// - Ignores the errors if the help flag is present but still returns the
+    ///   parse results if a required option is missing
+    //--------------------------------------------------------------------------
+    pub fn from_parse_results(
+      mut parse_results: ::commander::parse::parse_results::ParseResults,
+    ) -> Result<Self, ::commander::parse::parse_results::ParseResults> {
+      #(#conversions)*
+
+      if #help_check parse_results.has_errors() {
+        return Err(parse_results);
+      }
+
+      #(#required_checks)*
+
+      Ok(Self {
+        #(#field_values),*
+      })
+    }
+
+    //--------------------------------------------------------------------------
+    /// Parses the command-line arguments without converting them
+    ///
+    /// - Skips the first argument, the program name, as from env::args()
+    /// - Includes -V, -\-version, and -\-verbose unless no_version_options
+    //--------------------------------------------------------------------------
+    pub fn make_parse_results(
+      args: &[String],
+    ) -> ::commander::parse::parse_results::ParseResults {
+      let option_configs: Vec<
+        ::commander::print::option_config::OptionConfig,
+      > = #option_configs;
+
+      let parse_option_configs: Vec<
+        &::commander::parse::parse_option_config::ParseOptionConfig,
+      > = option_configs
+        .iter()
+        .map(
+          |option_config: &::commander::print::option_config::OptionConfig| {
+            &option_config.parse_option_config
+          },
+        )
+        .collect();
+
+      ::commander::parse::parse_input::ParseInput::new(
+        args,
+        &parse_option_configs,
+      )
+      .parse()
+    }
+
+    //--------------------------------------------------------------------------
+    /// Parses the command-line arguments into the struct
+    ///
+    /// - See from_parse_results for the errors
+    /// - Prints the version and exits first if -V or -\-version is present,
+    ///   unless no_version_options is set
+    //--------------------------------------------------------------------------
+    pub fn parse(
+      args: &[String],
+    ) -> Result<Self, ::commander::parse::parse_results::ParseResults> {
+      let parse_results: ::commander::parse::parse_results::ParseResults =
+        Self::make_parse_results(args);
+
+      #version_check
+
+      Self::from_parse_results(parse_results)
+    }
+  }
+}
+
+//------------------------------------------------------------------------------
+/// The option configuration from the field name, type, and attributes
+//------------------------------------------------------------------------------
+fn parse_field_config(field: &Field) -> ::syn::Result<FieldConfig> {
+  let Some(ident) = field.ident.clone() else {
+    return Err(::syn::Error::new_spanned(field, "Field must be named"));
+  };
+
+  let (field_kind, value_type): (FieldKind, Type) = make_field_kind(&field.ty);
+
+  let doc_paragraphs: Vec<String> = make_doc_paragraphs(&field.attrs);
+
+  let mut field_config: FieldConfig = FieldConfig {
+    brief_description: doc_paragraphs.first().cloned(),
+    deprecated: false,
+    display_order: None,
+    field_kind,
+    heading: None,
+    hidden: false,
+    id: ident.to_string().trim_start_matches("r#").to_string(),
+    long_description: (doc_paragraphs.len() > 1)
+      .then(|| doc_paragraphs[1..].join("\n\n")),
+    name_long: make_name_long(&ident),
+    ident,
+    name_short: None,
+    replacement: None,
+    value_name: (field_kind == FieldKind::OptionalBool)
+      .then(|| "BOOL".to_string()),
+    value_type,
+  };
+
+  for attr in &field.attrs {
+    if !attr.path().is_ident("commander") {
+      continue;
+    }
+
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("deprecated") {
+        field_config.deprecated = true;
+
+        Ok(())
+      } else if meta.path.is_ident("display_order") {
+        field_config.display_order =
+          Some(meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+
+        Ok(())
+      } else if meta.path.is_ident("heading") {
+        field_config.heading = Some(meta.value()?.parse::<LitStr>()?.value());
+
+        Ok(())
+      } else if meta.path.is_ident("hidden") {
+        field_config.hidden = true;
+
+        Ok(())
+      } else if meta.path.is_ident("id") {
+        field_config.id = meta.value()?.parse::<LitStr>()?.value();
+
+        Ok(())
+      } else if meta.path.is_ident("long") {
+        field_config.name_long = meta.value()?.parse::<LitStr>()?.value();
+
+        Ok(())
+      } else if meta.path.is_ident("replaced_by") {
+        field_config.replacement =
+          Some(meta.value()?.parse::<LitStr>()?.value());
+
+        Ok(())
+      } else if meta.path.is_ident("short") {
+        field_config.name_short =
+          Some(meta.value()?.parse::<LitChar>()?.value());
+
+        Ok(())
+      } else if meta.path.is_ident("value_name") {
+        field_config.value_name =
+          Some(meta.value()?.parse::<LitStr>()?.value());
+
+        Ok(())
+      } else {
+        Err(meta.error(
+          "Expected deprecated, display_order, heading, hidden, id, long, \
+           replaced_by, short, or value_name",
+        ))
+      }
+    })?;
+  }
+
+  Ok(field_config)
+}
+
+//------------------------------------------------------------------------------
+/// The application information from the struct doc comment and attributes
+//------------------------------------------------------------------------------
+fn parse_struct_config(attrs: &[Attribute]) -> ::syn::Result<StructConfig> {
+  let doc_paragraphs: Vec<String> = make_doc_paragraphs(attrs);
+
+  let mut struct_config: StructConfig = StructConfig {
+    about: doc_paragraphs.first().cloned(),
+    long_about: (doc_paragraphs.len() > 1).then(|| doc_paragraphs.join("\n\n")),
+    ..Default::default()
+  };
+
+  for attr in attrs {
+    if !attr.path().is_ident("commander") {
+      continue;
+    }
+
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("no_version_options") {
+        struct_config.no_version_options = true;
+
+        return Ok(());
+      }
+
+      let target: &mut Option<String> = if meta.path.is_ident("app_name") {
+        &mut struct_config.app_name
+      } else if meta.path.is_ident("bug_report") {
+        &mut struct_config.bug_report
+      } else if meta.path.is_ident("command_name") {
+        &mut struct_config.command_name
+      } else if meta.path.is_ident("contact") {
+        &mut struct_config.contact
+      } else if meta.path.is_ident("copyright") {
+        &mut struct_config.copyright
+      } else if meta.path.is_ident("homepage") {
+        &mut struct_config.homepage
+      } else if meta.path.is_ident("license") {
+        &mut struct_config.license
+      } else if meta.path.is_ident("version") {
+        &mut struct_config.version
+      } else {
+        return Err(meta.error(
+          "Expected app_name, bug_report, command_name, contact, copyright, \
+           homepage, license, no_version_options, or version",
+        ));
+      };
+
+      *target = Some(meta.value()?.parse::<LitStr>()?.value());
+
+      Ok(())
+    })?;
+  }
+
+  Ok(struct_config)
+}
+
+//------------------------------------------------------------------------------
+/// Some string literal or None
+//------------------------------------------------------------------------------
+fn quote_option_str(value: &Option<String>) -> TokenStream2 {
+  match value {
+    Some(value) => quote! { Some(#value) },
+    None => quote! { None },
+  }
+}
@@ -0,0 +1,302 @@
+//==============================================================================
+//! Unit tests for module commander_derive
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+fn parse_derive_input(source: &str) -> DeriveInput {
+  ::syn::parse_str(source).expect("Invalid test source")
+}
+
+fn parse_type(source: &str) -> Type {
+  ::syn::parse_str(source).expect("Invalid test type")
+}
+
+#[test]
+fn test_expand_0() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    /// Test about
+    #[commander(command_name = "test")]
+    struct TestOptions {
+      /// Test name
+      #[commander(short = 'n')]
+      test_name: Option<String>,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains("about : Some (\"Test about\")"));
+
+  assert!(actual.contains("command_name : Some (\"test\")"));
+
+  assert!(actual.contains("long (\"test-name\") . id (\"test_name\")"));
+
+  assert!(actual.contains(". short ('n')"));
+
+  assert!(actual.contains(". help (\"Test name\")"));
+}
+
+#[test]
+fn test_expand_1() {
+  let test_derive_input: DeriveInput =
+    parse_derive_input("struct TestOptions(bool);");
+
+  assert!(expand(&test_derive_input).is_err());
+}
+
+#[test]
+fn test_expand_2() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      #[commander(unknown = "x")]
+      test: bool,
+    }
+    "#,
+  );
+
+  assert!(expand(&test_derive_input).is_err());
+}
+
+#[test]
+fn test_expand_3() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      interactive: Option<bool>,
+      #[commander(value_name = "FILE")]
+      input: Option<String>,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains(". value_name (\"BOOL\")"));
+
+  assert!(actual.contains(". value_name (\"FILE\")"));
+}
+
+#[test]
+fn test_expand_4() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    /// Test about
+    ///
+    /// Test long about
+    struct TestOptions {
+      /// Test brief
+      ///
+      /// Test long
+      test: bool,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains("about : Some (\"Test about\")"));
+
+  assert!(
+    actual.contains("long_about : Some (\"Test about\\n\\nTest long about\")")
+  );
+
+  assert!(actual.contains(". help (\"Test brief\")"));
+
+  assert!(actual.contains(". long_help (\"Test long\")"));
+}
+
+#[test]
+fn test_expand_5() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      #[commander(display_order = 1, heading = "Input")]
+      input: Option<String>,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains(". display_order (1usize)"));
+
+  assert!(actual.contains(". heading (\"Input\")"));
+}
+
+#[test]
+fn test_expand_6() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      #[commander(hidden)]
+      debug: bool,
+      #[commander(replaced_by = "--output")]
+      out: Option<String>,
+      #[commander(deprecated)]
+      old: bool,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains(". hidden ()"));
+
+  assert!(actual.contains(". replaced_by (\"--output\")"));
+
+  assert!(actual.contains(". deprecated ()"));
+}
+
+#[test]
+fn test_expand_7() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    #[commander(license = "MIT", version = "1.2.3")]
+    struct TestOptions {
+      quiet: bool,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains("license : Some (\"MIT\")"));
+
+  assert!(actual.contains("version : Some (\"1.2.3\")"));
+
+  assert!(actual.contains("add_version_options"));
+
+  assert!(actual.contains("version_options : true"));
+
+  assert!(actual.contains("version_and_exit"));
+
+  assert!(!actual.contains("process"));
+}
+
+#[test]
+fn test_expand_8() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      help: bool,
+      quiet: bool,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains("if ! field_help && parse_results . has_errors ()"));
+}
+
+#[test]
+fn test_expand_9() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    #[commander(no_version_options)]
+    struct TestOptions {
+      #[commander(short = 'v')]
+      verbose: bool,
+    }
+    "#,
+  );
+
+  let actual: String = expand(&test_derive_input).unwrap().to_string();
+
+  assert!(actual.contains("version : Some (env ! (\"CARGO_PKG_VERSION\"))"));
+
+  assert!(actual.contains("version_options : false"));
+
+  assert!(!actual.contains("add_version_options"));
+
+  assert!(!actual.contains("version_and_exit"));
+}
+
+#[test]
+fn test_expand_10() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    struct TestOptions {
+      verbose: bool,
+    }
+    "#,
+  );
+
+  assert!(expand(&test_derive_input).is_err());
+}
+
+#[test]
+fn test_make_doc_paragraphs_0() {
+  let test_derive_input: DeriveInput = parse_derive_input(
+    r#"
+    /// Line one
+    /// continued
+    ///
+    ///
+    ///   Line two
+    struct TestOptions {}
+    "#,
+  );
+
+  let expected: Vec<String> = vec![
+    "Line one continued".to_string(),
+    "Line two".to_string(),
+  ];
+
+  let actual: Vec<String> = make_doc_paragraphs(&test_derive_input.attrs);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_field_kind_0() {
+  let actual: Vec<FieldKind> = [
+    "bool",
+    "Option<bool>",
+    "Option<u32>",
+    "Vec<String>",
+    "std::path::PathBuf",
+  ]
+  .iter()
+  .map(|source: &&str| make_field_kind(&parse_type(source)).0)
+  .collect();
+
+  let expected: Vec<FieldKind> = vec![
+    FieldKind::Flag,
+    FieldKind::OptionalBool,
+    FieldKind::Optional,
+    FieldKind::Repeated,
+    FieldKind::Required,
+  ];
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_field_kind_1() {
+  let (_, actual): (FieldKind, Type) =
+    make_field_kind(&parse_type("Option<u32>"));
+
+  assert_eq!(quote!(#actual).to_string(), "u32");
+}
+
+#[test]
+fn test_make_name_long_0() {
+  let test_ident: Ident =
+    Ident::new_raw("dry_run", ::proc_macro2::Span::call_site());
+
+  assert_eq!(make_name_long(&test_ident), "dry-run");
+}
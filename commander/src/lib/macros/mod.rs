@@ -0,0 +1,121 @@
+//==============================================================================
+//! Module for declarative macros
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Declares const OptionConfig items and an array of them
+///
+/// - Each item starts with the long name which is also the default id
+/// - Each entry in the braces calls the OptionConfig builder method of the
+///   same name, with the value after the colon as the argument if any
+/// - The array ends with the built-in -V, -\-version, and -\-verbose options
+///   from version_option unless declared `without_version_options`
+/// - The array is checked at compile time using assert_valid_slice, so an
+///   item which clashes with the built-in options fails the build
+/// - An alternative to the derive macro for crates without proc macros
+///
+/// ```
+/// use commander::option_configs;
+/// use commander::parse::duplicate_policy::DuplicatePolicy;
+///
+/// option_configs! {
+///   pub const OPTION_CONFIGS;
+///
+///   pub const OPTION_CONFIG_N = "name" => {
+///     short: 'n',
+///     required_value,
+///     duplicate_policy: DuplicatePolicy::LastWins,
+///     help: "Any value not starting with a hyphen (-)",
+///   };
+///
+///   pub const OPTION_CONFIG_Q = "quiet" => {
+///     short: 'q',
+///     help: "Suppress the exclamation mark",
+///   };
+/// }
+///
+/// assert_eq!(OPTION_CONFIGS.len(), 4);
+///
+/// option_configs! {
+///   const OTHER_OPTION_CONFIGS without_version_options;
+///
+///   const OPTION_CONFIG_V = "verbose" => {
+///     short: 'v',
+///   };
+/// }
+///
+/// assert_eq!(OTHER_OPTION_CONFIGS.len(), 1);
+/// ```
+//------------------------------------------------------------------------------
+#[macro_export]
+macro_rules! option_configs {
+  (
+    @expand [$( $built_in:expr ),*]
+
+    $array_vis:vis const $array_name:ident;
+
+    $(
+      $item_vis:vis const $item_name:ident = $name_long:expr => {
+        $( $method:ident $( : $argument:expr )? ),* $(,)?
+      };
+    )*
+  ) => {
+    $(
+      $item_vis const $item_name:
+        $crate::print::option_config::OptionConfig<'static> =
+        $crate::print::option_config::OptionConfig::long($name_long)
+          $( .$method($( $argument )?) )*;
+    )*
+
+    $array_vis const $array_name: [
+      $crate::print::option_config::OptionConfig<'static>;
+      [$( stringify!($item_name), )* $( stringify!($built_in), )*].len()
+    ] = [$( $item_name, )* $( $built_in, )*];
+
+    const _: () =
+      $crate::print::option_config::OptionConfig::assert_valid_slice(
+        &$array_name,
+      );
+  };
+  (
+    $array_vis:vis const $array_name:ident without_version_options;
+
+    $( $items:tt )*
+  ) => {
+    $crate::option_configs! {
+      @expand []
+
+      $array_vis const $array_name;
+
+      $( $items )*
+    }
+  };
+  (
+    $array_vis:vis const $array_name:ident;
+
+    $( $items:tt )*
+  ) => {
+    $crate::option_configs! {
+      @expand [
+        $crate::print::version_option::VERSION_OPTION_CONFIG,
+        $crate::print::version_option::VERBOSE_OPTION_CONFIG
+      ]
+
+      $array_vis const $array_name;
+
+      $( $items )*
+    }
+  };
+}
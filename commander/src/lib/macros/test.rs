@@ -0,0 +1,89 @@
+//==============================================================================
+//! Unit tests for module macros
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::option_configs;
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::parse_option_name::ParseOptionName;
+use crate::parse::value_usage::ValueUsage;
+use crate::print::option_config::OptionConfig;
+use crate::print::version_option::VERBOSE_ID;
+use crate::print::version_option::VERSION_ID;
+
+const TEST_ID_0: &str = "TEST_ID_0";
+
+option_configs! {
+  const TEST_OPTION_CONFIGS;
+
+  const TEST_OPTION_CONFIG_0 = "TEST_NAME_0" => {
+    id: TEST_ID_0,
+    short: '0',
+    required_value,
+    required,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    help: "TEST_BRIEF_DESCRIPTION_0",
+  };
+
+  const TEST_OPTION_CONFIG_1 = "TEST_NAME_1" => {};
+}
+
+option_configs! {
+  const TEST_OPTION_CONFIGS_WITHOUT_VERSION without_version_options;
+
+  const TEST_OPTION_CONFIG_2 = "verbose" => {
+    short: 'V',
+  };
+}
+
+#[test]
+fn test_option_configs_0() {
+  let actual: OptionConfig = TEST_OPTION_CONFIG_0;
+
+  assert_eq!(actual.brief_description, Some("TEST_BRIEF_DESCRIPTION_0"));
+
+  assert_eq!(actual.parse_option_config.id, TEST_ID_0);
+
+  assert_eq!(
+    actual.parse_option_config.name,
+    ParseOptionName::Both {
+      name_long: "TEST_NAME_0",
+      name_short: '0',
+    }
+  );
+
+  assert!(actual.parse_option_config.required);
+
+  assert_eq!(
+    actual.parse_option_config.duplicate_policy,
+    DuplicatePolicy::LastWins
+  );
+
+  assert_eq!(actual.parse_option_config.value_usage, ValueUsage::Required);
+}
+
+#[test]
+fn test_option_configs_1() {
+  assert_eq!(TEST_OPTION_CONFIGS.len(), 4);
+
+  assert_eq!(TEST_OPTION_CONFIGS[1].parse_option_config.id, "TEST_NAME_1");
+
+  assert_eq!(TEST_OPTION_CONFIGS[1].brief_description, None);
+}
+
+#[test]
+fn test_option_configs_2() {
+  assert_eq!(TEST_OPTION_CONFIGS[2].parse_option_config.id, VERSION_ID);
+
+  assert_eq!(TEST_OPTION_CONFIGS[3].parse_option_config.id, VERBOSE_ID);
+
+  assert_eq!(TEST_OPTION_CONFIGS_WITHOUT_VERSION.len(), 1);
+}
@@ -0,0 +1,29 @@
+//==============================================================================
+//! Module for DuplicatePolicy
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+//------------------------------------------------------------------------------
+/// What a full parse does when an option occurs more than once
+///
+/// Occurrences with a parse error are always kept so that they are reported.
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+  /// Keeps every occurrence
+  Accumulate,
+  /// Marks every occurrence after the first with a DuplicateOption error
+  Error,
+  /// Keeps only the first occurrence
+  FirstWins,
+  /// Keeps only the last occurrence
+  LastWins,
+}
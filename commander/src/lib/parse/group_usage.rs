@@ -0,0 +1,38 @@
+//==============================================================================
+//! Module for GroupUsage
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+//------------------------------------------------------------------------------
+/// How many of the options in a group may be present
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GroupUsage {
+  AtLeastOne,
+  AtMostOne,
+  ExactlyOne,
+}
+
+impl GroupUsage {
+  //----------------------------------------------------------------------------
+  /// Whether the group allows none of its options to be present
+  //----------------------------------------------------------------------------
+  pub fn allows_none(&self) -> bool {
+    *self == GroupUsage::AtMostOne
+  }
+
+  //----------------------------------------------------------------------------
+  /// Whether the group allows more than one of its options to be present
+  //----------------------------------------------------------------------------
+  pub fn allows_multiple(&self) -> bool {
+    *self == GroupUsage::AtLeastOne
+  }
+}
@@ -2,21 +2,35 @@
 //! Modules to parse options from command-line arguments
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-04-02
-//! - Updated: 2024-08-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+pub mod duplicate_policy;
+pub mod group_usage;
 pub mod hyphenation_type;
+pub mod owned_parse_option_config;
+pub mod owned_parse_option_name;
+pub mod parse_config_issue;
+#[cfg(feature = "serde")]
+pub mod parse_deserializer;
 pub mod parse_error;
 pub mod parse_found;
+pub mod parse_group;
 pub mod parse_input;
 pub mod parse_iterator;
 pub mod parse_option_config;
+pub mod parse_option_error;
 pub mod parse_option_name;
 pub mod parse_output;
+pub mod parse_relation;
+pub mod parse_results;
+pub mod parse_rule_error;
+pub mod parse_suggestion;
+pub mod parse_warning;
 pub mod value_usage;
@@ -0,0 +1,78 @@
+//==============================================================================
+//! Module for OwnedParseOptionConfig
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::duplicate_policy::DuplicatePolicy;
+use super::owned_parse_option_name::OwnedParseOptionName;
+use super::parse_option_config::ParseOptionConfig;
+use super::value_usage::ValueUsage;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Option configuration metadata for parsing which owns its strings
+///
+/// - For options loaded at runtime such as from plugins or a spec file
+/// - Borrow a ParseOptionConfig view using as_parse_option_config
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedParseOptionConfig {
+  /// Whether a full parse warns that the option is deprecated
+  pub deprecated: bool,
+  /// What a full parse does when the option occurs more than once
+  pub duplicate_policy: DuplicatePolicy,
+  /// Whether the option is left out of -\-help unless all options are shown
+  pub hidden: bool,
+  pub id: String,
+  pub name: OwnedParseOptionName,
+  /// The option named in the deprecation warning to use instead
+  pub replacement: Option<String>,
+  /// Whether the option must be present in the command-line arguments
+  pub required: bool,
+  pub value_usage: ValueUsage,
+}
+
+impl OwnedParseOptionConfig {
+  //----------------------------------------------------------------------------
+  /// A borrowed view for use with ParseIterator and ParseInput
+  //----------------------------------------------------------------------------
+  pub fn as_parse_option_config(&self) -> ParseOptionConfig<'_> {
+    ParseOptionConfig {
+      deprecated: self.deprecated,
+      duplicate_policy: self.duplicate_policy,
+      hidden: self.hidden,
+      id: &self.id,
+      name: self.name.as_parse_option_name(),
+      replacement: self.replacement.as_deref(),
+      required: self.required,
+      value_usage: self.value_usage,
+    }
+  }
+}
+
+impl From<ParseOptionConfig<'_>> for OwnedParseOptionConfig {
+  fn from(parse_option_config: ParseOptionConfig<'_>) -> Self {
+    Self {
+      deprecated: parse_option_config.deprecated,
+      duplicate_policy: parse_option_config.duplicate_policy,
+      hidden: parse_option_config.hidden,
+      id: parse_option_config.id.to_string(),
+      name: parse_option_config.name.into(),
+      replacement: parse_option_config
+        .replacement
+        .map(|replacement: &str| replacement.to_string()),
+      required: parse_option_config.required,
+      value_usage: parse_option_config.value_usage,
+    }
+  }
+}
@@ -0,0 +1,101 @@
+//==============================================================================
+//! Unit tests for module owned_parse_option_config
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::parse_input::ParseInput;
+use crate::parse::parse_option_name::ParseOptionName;
+use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_results::ParseResults;
+
+use super::*;
+
+const TEST_PARSE_OPTION_CONFIG_0: ParseOptionConfig =
+  ParseOptionConfig::long("TEST").short('T').required_value();
+
+fn make_test_owned_parse_option_config() -> OwnedParseOptionConfig {
+  // Simulates a name which is only known at runtime
+  let name_long: String = [
+    "TE", "ST",
+  ]
+  .concat();
+
+  OwnedParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
+    id: name_long.clone(),
+    name: OwnedParseOptionName::Both {
+      name_long,
+      name_short: 'T',
+    },
+    replacement: None,
+    required: false,
+    value_usage: ValueUsage::Required,
+  }
+}
+
+#[test]
+fn test_as_parse_option_config_0() {
+  let test_owned_parse_option_config: OwnedParseOptionConfig =
+    make_test_owned_parse_option_config();
+
+  let actual: ParseOptionConfig =
+    test_owned_parse_option_config.as_parse_option_config();
+
+  assert_eq!(actual, TEST_PARSE_OPTION_CONFIG_0);
+}
+
+#[test]
+fn test_as_parse_option_config_1() {
+  let test_owned_parse_option_configs: Vec<OwnedParseOptionConfig> =
+    vec![make_test_owned_parse_option_config()];
+
+  let parse_option_configs: Vec<ParseOptionConfig> =
+    test_owned_parse_option_configs
+      .iter()
+      .map(OwnedParseOptionConfig::as_parse_option_config)
+      .collect();
+
+  let parse_option_config_refs: Vec<&ParseOptionConfig> =
+    parse_option_configs.iter().collect();
+
+  let test_args: Vec<String> = vec!["--TEST=A".to_string()];
+
+  let parse_results: ParseResults = ParseInput::from_args_without_program_name(
+    &test_args,
+    &parse_option_config_refs,
+  )
+  .parse();
+
+  assert_eq!(
+    parse_results
+      .get_last("TEST")
+      .and_then(|parse_output: ParseOutput| parse_output.value),
+    Some("A".to_string())
+  );
+}
+
+#[test]
+fn test_from_0() {
+  let actual: OwnedParseOptionConfig = TEST_PARSE_OPTION_CONFIG_0.into();
+
+  assert_eq!(actual, make_test_owned_parse_option_config());
+}
+
+#[test]
+fn test_from_1() {
+  let actual: OwnedParseOptionName = ParseOptionName::Short('T').into();
+
+  assert_eq!(actual, OwnedParseOptionName::Short('T'));
+
+  assert_eq!(actual.as_parse_option_name(), ParseOptionName::Short('T'));
+}
@@ -0,0 +1,68 @@
+//==============================================================================
+//! Module for OwnedParseOptionName
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::parse_option_name::ParseOptionName;
+
+//------------------------------------------------------------------------------
+/// An option name which owns its long name, for names known only at runtime
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OwnedParseOptionName {
+  Both {
+    name_long: String,
+    name_short: char,
+  },
+  Long(String),
+  Short(char),
+}
+
+impl OwnedParseOptionName {
+  //----------------------------------------------------------------------------
+  /// A borrowed view for use wherever a ParseOptionName is expected
+  //----------------------------------------------------------------------------
+  pub fn as_parse_option_name(&self) -> ParseOptionName<'_> {
+    match self {
+      OwnedParseOptionName::Both {
+        name_long,
+        name_short,
+      } => ParseOptionName::Both {
+        name_long,
+        name_short: *name_short,
+      },
+      OwnedParseOptionName::Long(name_long) => ParseOptionName::Long(name_long),
+      OwnedParseOptionName::Short(name_short) => {
+        ParseOptionName::Short(*name_short)
+      },
+    }
+  }
+}
+
+impl From<ParseOptionName<'_>> for OwnedParseOptionName {
+  fn from(parse_option_name: ParseOptionName<'_>) -> Self {
+    match parse_option_name {
+      ParseOptionName::Both {
+        name_long,
+        name_short,
+      } => OwnedParseOptionName::Both {
+        name_long: name_long.to_string(),
+        name_short,
+      },
+      ParseOptionName::Long(name_long) => {
+        OwnedParseOptionName::Long(name_long.to_string())
+      },
+      ParseOptionName::Short(name_short) => {
+        OwnedParseOptionName::Short(name_short)
+      },
+    }
+  }
+}
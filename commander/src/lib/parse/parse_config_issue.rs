@@ -0,0 +1,77 @@
+//==============================================================================
+//! Module for ParseConfigIssue
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+//------------------------------------------------------------------------------
+/// Problems found when validating the option configurations
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseConfigIssue {
+  /// A digit short name such as -1 clashes with a negative number argument
+  DigitNameShort {
+    id: String,
+    name_short: char,
+  },
+  /// Two option configurations share the same id
+  DuplicateId {
+    id: String,
+  },
+  /// Two option configurations share the same long name
+  DuplicateNameLong {
+    first_id: String,
+    id: String,
+    name_long: String,
+  },
+  /// Two option configurations share the same short name
+  DuplicateNameShort {
+    first_id: String,
+    id: String,
+    name_short: char,
+  },
+  /// An option configuration has an empty id
+  EmptyId,
+  /// An option configuration has an empty long name
+  EmptyNameLong {
+    id: String,
+  },
+  /// A required option is hidden from -\-help so users cannot discover it
+  HiddenRequired {
+    id: String,
+  },
+  /// A name contains an equals sign which separates the name from the value
+  NameContainsEquals {
+    id: String,
+    name: String,
+  },
+  /// A name starts with a hyphen which would never match an argument
+  NameStartsWithHyphen {
+    id: String,
+    name: String,
+  },
+  /// A relation or group refers to an id with no option configuration
+  UnknownId {
+    id: String,
+  },
+}
+
+impl ParseConfigIssue {
+  //----------------------------------------------------------------------------
+  /// Whether the issue might be intended and so is not treated as an error
+  //----------------------------------------------------------------------------
+  pub fn is_warning(&self) -> bool {
+    matches!(
+      self,
+      ParseConfigIssue::DigitNameShort { .. }
+        | ParseConfigIssue::HiddenRequired { .. }
+    )
+  }
+}
@@ -0,0 +1,400 @@
+//==============================================================================
+//! Module for deserializing ParseResults using serde
+//!
+//! - Enabled by the serde feature
+//! - Deserialize a struct using `T::deserialize(&parse_results)`
+//! - Maps option configuration ids to struct field names
+//! - A missing option is left to serde: an Option is None, a field with
+//!   `#[serde(default)]` has its default value, and another field is an error
+//! - Use `#[serde(default)]` for a bool flag and for a Vec of repeated options
+//! - A bool is converted as in ParseOutput::to_bool_result
+//! - A repeated option is a sequence of its values
+//! - Other values are converted from the string value of the last option
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{
+  self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use super::parse_output::ParseOutput;
+use super::parse_results::ParseResults;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// An error from deserializing the parse results
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseDeserializeError {
+  pub message: String,
+}
+
+impl de::Error for ParseDeserializeError {
+  fn custom<T: Display>(message: T) -> Self {
+    Self {
+      message: message.to_string(),
+    }
+  }
+}
+
+impl Display for ParseDeserializeError {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    formatter.write_str(&self.message)
+  }
+}
+
+impl std::error::Error for ParseDeserializeError {}
+
+//------------------------------------------------------------------------------
+/// Deserializes the known options as a map from option id to option values
+//------------------------------------------------------------------------------
+impl<'de> Deserializer<'de> for &'de ParseResults {
+  type Error = ParseDeserializeError;
+
+  fn deserialize_any<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    let ids: Vec<&'de str> = make_known_ids(self);
+
+    visitor.visit_map(OptionMapAccess::new(self, ids))
+  }
+
+  // Visits only the fields present so that serde handles the missing fields
+  fn deserialize_struct<V: Visitor<'de>>(
+    self,
+    _name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    let ids: Vec<&'de str> = make_known_ids(self)
+      .into_iter()
+      .filter(|id: &&'de str| fields.contains(id))
+      .collect();
+
+    visitor.visit_map(OptionMapAccess::new(self, ids))
+  }
+
+  forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+    bytes byte_buf option unit unit_struct newtype_struct seq tuple
+    tuple_struct map enum identifier ignored_any
+  }
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// The ids of the known options present, without repeats
+//------------------------------------------------------------------------------
+fn make_known_ids(parse_results: &ParseResults) -> Vec<&str> {
+  let mut ids: Vec<&str> = Vec::new();
+
+  for parse_output in &parse_results.parse_outputs {
+    if let Some(id) = parse_output.known.as_deref()
+      && !ids.contains(&id)
+    {
+      ids.push(id);
+    }
+  }
+
+  ids
+}
+
+// -----------------------------------------------------------------------------
+// private types
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// Deserializes the options with the same id as one value or a sequence
+//------------------------------------------------------------------------------
+struct OptionDeserializer<'de> {
+  id: &'de str,
+  parse_outputs: Vec<&'de ParseOutput>,
+}
+
+impl<'de> OptionDeserializer<'de> {
+  //----------------------------------------------------------------------------
+  /// The value of the last option converted using FromStr
+  //----------------------------------------------------------------------------
+  fn convert_last_value<T: FromStr>(&self) -> Result<T, ParseDeserializeError> {
+    let value: &str = self.get_last_value()?;
+
+    value.parse::<T>().map_err(|_| {
+      de::Error::custom(format!(
+        "Invalid value \"{}\" for option {}",
+        value, self.id
+      ))
+    })
+  }
+
+  //----------------------------------------------------------------------------
+  /// The last option or an error if it is missing or has an error
+  //----------------------------------------------------------------------------
+  fn get_last(&self) -> Result<&'de ParseOutput, ParseDeserializeError> {
+    let Some(parse_output) = self.parse_outputs.last() else {
+      return Err(de::Error::custom(format!("Missing option {}", self.id)));
+    };
+
+    if let Some(parse_error) = parse_output.error {
+      return Err(de::Error::custom(format!(
+        "{} for option {}",
+        parse_error, self.id
+      )));
+    }
+
+    Ok(parse_output)
+  }
+
+  //----------------------------------------------------------------------------
+  /// The value of the last option or an error if there is no value
+  //----------------------------------------------------------------------------
+  fn get_last_value(&self) -> Result<&'de str, ParseDeserializeError> {
+    self.get_last()?.value.as_deref().ok_or_else(|| {
+      de::Error::custom(format!("Missing value for option {}", self.id))
+    })
+  }
+}
+
+macro_rules! deserialize_from_str {
+  ($($method:ident => $visit:ident,)*) => {
+    $(
+      fn $method<V: Visitor<'de>>(
+        self,
+        visitor: V,
+      ) -> Result<V::Value, Self::Error> {
+        visitor.$visit(self.convert_last_value()?)
+      }
+    )*
+  };
+}
+
+impl<'de> Deserializer<'de> for OptionDeserializer<'de> {
+  type Error = ParseDeserializeError;
+
+  deserialize_from_str! {
+    deserialize_char => visit_char,
+    deserialize_f32 => visit_f32,
+    deserialize_f64 => visit_f64,
+    deserialize_i8 => visit_i8,
+    deserialize_i16 => visit_i16,
+    deserialize_i32 => visit_i32,
+    deserialize_i64 => visit_i64,
+    deserialize_i128 => visit_i128,
+    deserialize_u8 => visit_u8,
+    deserialize_u16 => visit_u16,
+    deserialize_u32 => visit_u32,
+    deserialize_u64 => visit_u64,
+    deserialize_u128 => visit_u128,
+  }
+
+  fn deserialize_any<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    if self.parse_outputs.len() > 1 {
+      return self.deserialize_seq(visitor);
+    }
+
+    match self.get_last()?.value.as_deref() {
+      Some(value) => visitor.visit_borrowed_str(value),
+      None => visitor.visit_bool(true),
+    }
+  }
+
+  fn deserialize_bool<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    if self.parse_outputs.is_empty() {
+      return visitor.visit_bool(false);
+    }
+
+    let parse_output: &ParseOutput = self.get_last()?;
+
+    match parse_output.to_bool_result() {
+      Ok(value) => visitor.visit_bool(value),
+      Err(parse_error) => Err(de::Error::custom(format!(
+        "{} for option {}",
+        parse_error, self.id
+      ))),
+    }
+  }
+
+  fn deserialize_enum<V: Visitor<'de>>(
+    self,
+    _name: &'static str,
+    _variants: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    let value: &'de str = self.get_last_value()?;
+
+    visitor.visit_enum(BorrowedStrDeserializer::new(value))
+  }
+
+  fn deserialize_newtype_struct<V: Visitor<'de>>(
+    self,
+    _name: &'static str,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_option<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    if self.parse_outputs.is_empty() {
+      visitor.visit_none()
+    } else {
+      visitor.visit_some(self)
+    }
+  }
+
+  fn deserialize_seq<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    visitor.visit_seq(OptionSeqAccess {
+      id: self.id,
+      parse_outputs: self.parse_outputs.into_iter(),
+    })
+  }
+
+  fn deserialize_str<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    visitor.visit_borrowed_str(self.get_last_value()?)
+  }
+
+  fn deserialize_string<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    self.deserialize_str(visitor)
+  }
+
+  fn deserialize_unit<V: Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    visitor.visit_unit()
+  }
+
+  forward_to_deserialize_any! {
+    bytes byte_buf unit_struct tuple tuple_struct map struct identifier
+    ignored_any
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Visits the option ids as keys and the options as values
+//------------------------------------------------------------------------------
+struct OptionMapAccess<'de> {
+  ids: IntoIter<&'de str>,
+  parse_results: &'de ParseResults,
+  pending_id: Option<&'de str>,
+}
+
+impl<'de> OptionMapAccess<'de> {
+  fn new(
+    parse_results: &'de ParseResults,
+    ids: Vec<&'de str>,
+  ) -> Self {
+    Self {
+      ids: ids.into_iter(),
+      parse_results,
+      pending_id: None,
+    }
+  }
+}
+
+impl<'de> MapAccess<'de> for OptionMapAccess<'de> {
+  type Error = ParseDeserializeError;
+
+  fn next_key_seed<K: DeserializeSeed<'de>>(
+    &mut self,
+    seed: K,
+  ) -> Result<Option<K::Value>, Self::Error> {
+    let Some(id) = self.ids.next() else {
+      return Ok(None);
+    };
+
+    self.pending_id = Some(id);
+
+    seed.deserialize(BorrowedStrDeserializer::new(id)).map(Some)
+  }
+
+  fn next_value_seed<V: DeserializeSeed<'de>>(
+    &mut self,
+    seed: V,
+  ) -> Result<V::Value, Self::Error> {
+    let Some(id) = self.pending_id.take() else {
+      return Err(de::Error::custom("Value requested before key"));
+    };
+
+    let parse_outputs: Vec<&'de ParseOutput> = self
+      .parse_results
+      .parse_outputs
+      .iter()
+      .filter(|parse_output: &&ParseOutput| {
+        parse_output.known.as_deref() == Some(id)
+      })
+      .collect();
+
+    seed.deserialize(OptionDeserializer {
+      id,
+      parse_outputs,
+    })
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Visits each of the options with the same id as a sequence element
+//------------------------------------------------------------------------------
+struct OptionSeqAccess<'de> {
+  id: &'de str,
+  parse_outputs: IntoIter<&'de ParseOutput>,
+}
+
+impl<'de> SeqAccess<'de> for OptionSeqAccess<'de> {
+  type Error = ParseDeserializeError;
+
+  fn next_element_seed<T: DeserializeSeed<'de>>(
+    &mut self,
+    seed: T,
+  ) -> Result<Option<T::Value>, Self::Error> {
+    let Some(parse_output) = self.parse_outputs.next() else {
+      return Ok(None);
+    };
+
+    seed
+      .deserialize(OptionDeserializer {
+        id: self.id,
+        parse_outputs: vec![parse_output],
+      })
+      .map(Some)
+  }
+}
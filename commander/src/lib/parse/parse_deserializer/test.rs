@@ -0,0 +1,197 @@
+//==============================================================================
+//! Unit tests for module parse_deserializer
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::parse::parse_input::ParseInput;
+use crate::parse::parse_option_config::ParseOptionConfig;
+
+use super::*;
+
+const TEST_PARSE_OPTION_CONFIGS: &[&ParseOptionConfig] = &[
+  &ParseOptionConfig::long("count").short('c').required_value(),
+  &ParseOptionConfig::long("interactive")
+    .short('i')
+    .optional_value(),
+  &ParseOptionConfig::long("level").required_value(),
+  &ParseOptionConfig::long("name").short('n').required_value(),
+  &ParseOptionConfig::long("quiet").short('q'),
+  &ParseOptionConfig::long("tag").required_value(),
+];
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum TestLevel {
+  High,
+  Low,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct TestOptions {
+  count: u32,
+  #[serde(default)]
+  interactive: bool,
+  level: Option<TestLevel>,
+  name: Option<String>,
+  #[serde(default)]
+  quiet: bool,
+  #[serde(default)]
+  tag: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct TestDefaultOptions {
+  #[serde(default = "make_default_count")]
+  count: u32,
+  #[serde(default)]
+  level: Option<TestLevel>,
+  #[serde(default)]
+  name: String,
+}
+
+fn make_default_count() -> u32 {
+  7
+}
+
+fn parse(args: &[&str]) -> ParseResults {
+  let args: Vec<String> =
+    args.iter().map(|arg: &&str| arg.to_string()).collect();
+
+  ParseInput::from_args_without_program_name(&args, TEST_PARSE_OPTION_CONFIGS)
+    .parse()
+}
+
+#[test]
+fn test_deserialize_0() {
+  let parse_results: ParseResults = parse(&[
+    "-c=3",
+    "-i=no",
+    "--level=high",
+    "--tag=a",
+    "--tag=b",
+  ]);
+
+  let expected: TestOptions = TestOptions {
+    count: 3,
+    interactive: false,
+    level: Some(TestLevel::High),
+    name: None,
+    quiet: false,
+    tag: vec![
+      "a".to_string(),
+      "b".to_string(),
+    ],
+  };
+
+  let actual: TestOptions = TestOptions::deserialize(&parse_results).unwrap();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_deserialize_1() {
+  let parse_results: ParseResults = parse(&[
+    "-c=3", "-qi", "-n=Test",
+  ]);
+
+  let actual: TestOptions = TestOptions::deserialize(&parse_results).unwrap();
+
+  assert!(actual.interactive);
+
+  assert!(actual.quiet);
+
+  assert_eq!(actual.name, Some("Test".to_string()));
+
+  assert_eq!(actual.tag, Vec::<String>::new());
+}
+
+#[test]
+fn test_deserialize_2() {
+  let parse_results: ParseResults = parse(&["-q"]);
+
+  let actual: Result<TestOptions, ParseDeserializeError> =
+    TestOptions::deserialize(&parse_results);
+
+  assert_eq!(
+    actual.unwrap_err().message,
+    "missing field `count`".to_string()
+  );
+}
+
+#[test]
+fn test_deserialize_3() {
+  let parse_results: ParseResults = parse(&["-c=three"]);
+
+  let actual: Result<TestOptions, ParseDeserializeError> =
+    TestOptions::deserialize(&parse_results);
+
+  assert_eq!(
+    actual.unwrap_err().message,
+    "Invalid value \"three\" for option count".to_string()
+  );
+}
+
+#[test]
+fn test_deserialize_4() {
+  let parse_results: ParseResults = parse(&[
+    "--tag=a", "-n=Test",
+  ]);
+
+  let expected: HashMap<String, String> = HashMap::from([
+    ("name".to_string(), "Test".to_string()),
+    ("tag".to_string(), "a".to_string()),
+  ]);
+
+  let actual: HashMap<String, String> =
+    HashMap::deserialize(&parse_results).unwrap();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_deserialize_5() {
+  let parse_results: ParseResults = parse(&["-q"]);
+
+  let expected: TestDefaultOptions = TestDefaultOptions {
+    count: 7,
+    level: None,
+    name: String::new(),
+  };
+
+  let actual: TestDefaultOptions =
+    TestDefaultOptions::deserialize(&parse_results).unwrap();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_deserialize_6() {
+  let parse_results: ParseResults = parse(&[
+    "-c=3",
+    "--level=low",
+    "-n=Test",
+  ]);
+
+  let expected: TestDefaultOptions = TestDefaultOptions {
+    count: 3,
+    level: Some(TestLevel::Low),
+    name: "Test".to_string(),
+  };
+
+  let actual: TestDefaultOptions =
+    TestDefaultOptions::deserialize(&parse_results).unwrap();
+
+  assert_eq!(actual, expected);
+}
@@ -1,23 +1,56 @@
 //==============================================================================
-//! Module for CommanderParseError
+//! Module for ParseError
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-27
-//! - Updated: 2024-07-07
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
 //------------------------------------------------------------------------------
 /// Errors that can occur when parsing an option from the command-line arguments
 //------------------------------------------------------------------------------
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ParseError {
+  /// The option occurred earlier and its duplicate policy is Error
+  DuplicateOption {
+    /// The argument index of the first occurrence
+    first_arg_index: usize,
+  },
   InvalidValue,
   RequiredValueMissing,
   ValueMissingAfterEquals,
   VerbotenValuePresent,
 }
+
+impl Display for ParseError {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    match self {
+      ParseError::DuplicateOption {
+        first_arg_index,
+      } => write!(formatter, "Duplicate of argument index {first_arg_index}"),
+      ParseError::InvalidValue => formatter.write_str("Invalid value"),
+      ParseError::RequiredValueMissing => {
+        formatter.write_str("Required value missing")
+      },
+      ParseError::ValueMissingAfterEquals => {
+        formatter.write_str("Value missing after equals")
+      },
+      ParseError::VerbotenValuePresent => {
+        formatter.write_str("Verboten value present")
+      },
+    }
+  }
+}
+
+impl Error for ParseError {}
@@ -2,20 +2,31 @@
 //! Module for ParseFound
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-07-12
-//! - Updated: 2024-07-12
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use std::fmt::{self, Display, Formatter};
+
 #[cfg(test)]
 mod test;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ParseFound {
+  /// Not in the command-line arguments but implied by another option
+  Implied {
+    /// The argument index of the option that implied this option
+    arg_index: usize,
+    /// The id of the option that implied this option
+    implied_by: String,
+    /// The hyphenated name of this option from its option configuration
+    name: String,
+  },
   Long {
     arg_index: usize,
     name_long: String,
@@ -30,6 +41,10 @@ pub enum ParseFound {
 impl ParseFound {
   pub fn get_arg_index(&self) -> usize {
     match self {
+      ParseFound::Implied {
+        arg_index,
+        ..
+      } => *arg_index,
       ParseFound::Long {
         arg_index,
         ..
@@ -41,8 +56,25 @@ impl ParseFound {
     }
   }
 
+  //----------------------------------------------------------------------------
+  /// The character index within the argument if found as a short name
+  //----------------------------------------------------------------------------
+  pub fn get_char_index(&self) -> Option<usize> {
+    match self {
+      ParseFound::Short {
+        char_index,
+        ..
+      } => Some(*char_index),
+      _ => None,
+    }
+  }
+
   pub fn get_name(&self) -> String {
     match self {
+      ParseFound::Implied {
+        name,
+        ..
+      } => name.trim_start_matches('-').to_string(),
       ParseFound::Long {
         name_long,
         ..
@@ -53,4 +85,60 @@ impl ParseFound {
       } => name_short.to_string(),
     }
   }
+
+  //----------------------------------------------------------------------------
+  /// The name with two hyphens if found as a long name, otherwise one hyphen
+  ///
+  /// - An implied option has the hyphenated name from its configuration
+  //----------------------------------------------------------------------------
+  pub fn make_hyphenated_name(&self) -> String {
+    match self {
+      ParseFound::Implied {
+        name,
+        ..
+      } => name.clone(),
+      ParseFound::Long {
+        name_long,
+        ..
+      } => format!("--{name_long}"),
+      ParseFound::Short {
+        name_short,
+        ..
+      } => format!("-{name_short}"),
+    }
+  }
+}
+
+//------------------------------------------------------------------------------
+/// A description of where the option was found
+//------------------------------------------------------------------------------
+impl Display for ParseFound {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    match self {
+      ParseFound::Implied {
+        arg_index,
+        implied_by,
+        ..
+      } => write!(
+        formatter,
+        "argument index {arg_index}: \"{}\" implied by \"{implied_by}\"",
+        self.get_name()
+      ),
+      ParseFound::Long {
+        arg_index,
+        name_long,
+      } => write!(formatter, "argument index {arg_index}: \"{name_long}\""),
+      ParseFound::Short {
+        arg_index,
+        char_index,
+        name_short,
+      } => write!(
+        formatter,
+        "argument index {arg_index} character index {char_index}: '{name_short}'"
+      ),
+    }
+  }
 }
@@ -2,10 +2,10 @@
 //! Unit tests for module parse_found
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-07-12
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -74,3 +74,63 @@ fn test_get_name_1() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_get_name_2() {
+  let test_parse_found: ParseFound = ParseFound::Implied {
+    arg_index: 1,
+    implied_by: "TEST_ID".to_string(),
+    name: format!("--{TEST_NAME_LONG}"),
+  };
+
+  let expected: String = TEST_NAME_LONG.to_string();
+
+  let actual: String = test_parse_found.get_name();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_hyphenated_name_0() {
+  let test_parse_found: ParseFound = ParseFound::Implied {
+    arg_index: 1,
+    implied_by: "ci".to_string(),
+    name: "-k".to_string(),
+  };
+
+  assert_eq!(test_parse_found.make_hyphenated_name(), "-k");
+}
+
+#[test]
+fn test_make_hyphenated_name_1() {
+  let test_parse_found: ParseFound = ParseFound::Long {
+    arg_index: 1,
+    name_long: "x".to_string(),
+  };
+
+  assert_eq!(test_parse_found.make_hyphenated_name(), "--x");
+}
+
+#[test]
+fn test_make_hyphenated_name_2() {
+  let test_parse_found: ParseFound = ParseFound::Short {
+    arg_index: 1,
+    char_index: 0,
+    name_short: 'x',
+  };
+
+  assert_eq!(test_parse_found.make_hyphenated_name(), "-x");
+}
+
+#[test]
+fn test_to_string_0() {
+  let test_parse_found: ParseFound = ParseFound::Short {
+    arg_index: 1,
+    char_index: 2,
+    name_short: 's',
+  };
+
+  let expected: &str = "argument index 1 character index 2: 's'";
+
+  assert_eq!(test_parse_found.to_string(), expected);
+}
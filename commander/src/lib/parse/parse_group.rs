@@ -0,0 +1,28 @@
+//==============================================================================
+//! Module for ParseGroup
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::group_usage::GroupUsage;
+
+//------------------------------------------------------------------------------
+/// A group of options identified by their configuration ids
+///
+/// - Checked after a full parse of the command-line arguments
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseGroup<'a> {
+  /// How many of the options in the group may be present
+  pub group_usage: GroupUsage,
+  pub id: &'a str,
+  /// The ids of the option configurations in the group
+  pub ids: &'a [&'a str],
+}
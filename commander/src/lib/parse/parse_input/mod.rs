@@ -2,18 +2,27 @@
 //! Module for ParseInput.
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-08-04
-//! - Updated: 2024-08-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 //==============================================================================
 
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::parse_config_issue::ParseConfigIssue;
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
+use crate::parse::parse_group::ParseGroup;
 use crate::parse::parse_iterator::ParseIterator;
 use crate::parse::parse_option_config::ParseOptionConfig;
 use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_relation::ParseRelation;
+use crate::parse::parse_results::ParseResults;
+use crate::parse::parse_rule_error::ParseRuleError;
+use crate::parse::parse_warning::ParseWarning;
 
 #[cfg(test)]
 mod test;
@@ -22,8 +31,559 @@ mod test;
 pub struct ParseInput<'a> {
   /// The command-line arguments
   pub args: &'a [String],
+  /// The groups of known options
+  pub parse_groups: &'a [ParseGroup<'a>],
   /// The known command-line arguments options
   pub parse_option_configs: &'a [&'a ParseOptionConfig<'a>],
+  /// The relationships between the known options
+  pub parse_relations: &'a [ParseRelation<'a>],
+  /// How many command-line arguments to skip, such as 1 for the program name
+  pub skip_arg: usize,
+}
+
+impl<'a> ParseInput<'a> {
+  //----------------------------------------------------------------------------
+  /// The command-line arguments without the program name and known options
+  ///
+  /// - Use when the arguments do not start with the program name
+  //----------------------------------------------------------------------------
+  pub const fn from_args_without_program_name(
+    args: &'a [String],
+    parse_option_configs: &'a [&'a ParseOptionConfig<'a>],
+  ) -> Self {
+    Self {
+      args,
+      parse_groups: &[],
+      parse_option_configs,
+      parse_relations: &[],
+      skip_arg: 0,
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// The command-line arguments and known options without groups or relations
+  ///
+  /// - Skips the first argument, the program name, as from env::args()
+  /// - The argument indices in the outputs still count the program name
+  //----------------------------------------------------------------------------
+  pub const fn new(
+    args: &'a [String],
+    parse_option_configs: &'a [&'a ParseOptionConfig<'a>],
+  ) -> Self {
+    Self {
+      args,
+      parse_groups: &[],
+      parse_option_configs,
+      parse_relations: &[],
+      skip_arg: 1,
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Parses all of the options and then checks the options as a whole
+  ///
+  /// - Applies the duplicate policy of each option that occurs more than once
+  /// - Adds the options implied by the options present
+  /// - Reports all of the missing required options in a single rule error
+  /// - Reports the conflicting options and the missing requirements
+  /// - Reports the groups with too few or too many options present
+  /// - Warns about the deprecated options present without printing
+  /// - Panics in debug builds if validate finds issues other than warnings
+  //----------------------------------------------------------------------------
+  pub fn parse(&self) -> ParseResults {
+    #[cfg(debug_assertions)]
+    self.assert_valid();
+
+    let mut parse_outputs: Vec<ParseOutput> = self.into_iter().collect();
+
+    self.apply_duplicate_policies(&mut parse_outputs);
+
+    let warnings: Vec<ParseWarning> = self.check_deprecated(&parse_outputs);
+
+    self.add_implied(&mut parse_outputs);
+
+    let mut rule_errors: Vec<ParseRuleError> = Vec::new();
+
+    if let Some(rule_error) = self.check_required(&parse_outputs) {
+      rule_errors.push(rule_error);
+    }
+
+    rule_errors.append(&mut self.check_relations(&parse_outputs));
+
+    rule_errors.append(&mut self.check_groups(&parse_outputs));
+
+    ParseResults {
+      parse_outputs,
+      rule_errors,
+      warnings,
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Checks the option configurations, relations, and groups for mistakes
+  ///
+  /// - Reports duplicate ids, long names, and short names
+  /// - Reports empty ids and empty long names
+  /// - Reports names containing "=" or starting with "-"
+  /// - Warns about digit short names which clash with negative numbers
+  /// - Warns about hidden options which are also required
+  /// - Reports relation and group ids with no option configuration
+  /// - Can be called at startup, for example from a unit test
+  //----------------------------------------------------------------------------
+  pub fn validate(&self) -> Vec<ParseConfigIssue> {
+    let mut issues: Vec<ParseConfigIssue> = Vec::new();
+
+    for (index, parse_option_config) in
+      self.parse_option_configs.iter().enumerate()
+    {
+      let id: &str = parse_option_config.id;
+
+      let earlier: &[&ParseOptionConfig] = &self.parse_option_configs[..index];
+
+      if id.is_empty() {
+        issues.push(ParseConfigIssue::EmptyId);
+      } else if earlier
+        .iter()
+        .any(|earlier_config: &&ParseOptionConfig| earlier_config.id == id)
+      {
+        issues.push(ParseConfigIssue::DuplicateId {
+          id: id.to_string(),
+        });
+      }
+
+      if let Some(name_long) = parse_option_config.name.get_name_long() {
+        if name_long.is_empty() {
+          issues.push(ParseConfigIssue::EmptyNameLong {
+            id: id.to_string(),
+          });
+        }
+
+        check_name(&mut issues, id, name_long);
+
+        if let Some(first_config) =
+          earlier.iter().find(|earlier_config: &&&ParseOptionConfig| {
+            earlier_config.name.get_name_long() == Some(name_long)
+          })
+        {
+          issues.push(ParseConfigIssue::DuplicateNameLong {
+            first_id: first_config.id.to_string(),
+            id: id.to_string(),
+            name_long: name_long.to_string(),
+          });
+        }
+      }
+
+      if let Some(name_short) = parse_option_config.name.get_name_short() {
+        if name_short.is_ascii_digit() {
+          issues.push(ParseConfigIssue::DigitNameShort {
+            id: id.to_string(),
+            name_short,
+          });
+        }
+
+        check_name(&mut issues, id, &name_short.to_string());
+
+        if let Some(first_config) =
+          earlier.iter().find(|earlier_config: &&&ParseOptionConfig| {
+            earlier_config.name.get_name_short() == Some(name_short)
+          })
+        {
+          issues.push(ParseConfigIssue::DuplicateNameShort {
+            first_id: first_config.id.to_string(),
+            id: id.to_string(),
+            name_short,
+          });
+        }
+      }
+
+      if parse_option_config.hidden && parse_option_config.required {
+        issues.push(ParseConfigIssue::HiddenRequired {
+          id: id.to_string(),
+        });
+      }
+    }
+
+    let mut referenced_ids: Vec<&str> = Vec::new();
+
+    for parse_relation in self.parse_relations {
+      match parse_relation {
+        ParseRelation::Conflicts {
+          conflicting_id: other_id,
+          id,
+        }
+        | ParseRelation::Implies {
+          id,
+          implied_id: other_id,
+          ..
+        }
+        | ParseRelation::Requires {
+          id,
+          required_id: other_id,
+        } => {
+          referenced_ids.push(id);
+
+          referenced_ids.push(other_id);
+        },
+      }
+    }
+
+    for parse_group in self.parse_groups {
+      referenced_ids.extend_from_slice(parse_group.ids);
+    }
+
+    for referenced_id in referenced_ids {
+      let is_known: bool = self.parse_option_configs.iter().any(
+        |parse_option_config: &&ParseOptionConfig| {
+          parse_option_config.id == referenced_id
+        },
+      );
+
+      let issue: ParseConfigIssue = ParseConfigIssue::UnknownId {
+        id: referenced_id.to_string(),
+      };
+
+      if !is_known && !issues.contains(&issue) {
+        issues.push(issue);
+      }
+    }
+
+    issues
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the groups of known options
+  //----------------------------------------------------------------------------
+  pub const fn with_groups(
+    mut self,
+    parse_groups: &'a [ParseGroup<'a>],
+  ) -> Self {
+    self.parse_groups = parse_groups;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the relationships between the known options
+  //----------------------------------------------------------------------------
+  pub const fn with_relations(
+    mut self,
+    parse_relations: &'a [ParseRelation<'a>],
+  ) -> Self {
+    self.parse_relations = parse_relations;
+
+    self
+  }
+
+  // ---------------------------------------------------------------------------
+  // private functions
+  // ---------------------------------------------------------------------------
+
+  fn add_implied(
+    &self,
+    parse_outputs: &mut Vec<ParseOutput>,
+  ) {
+    // Repeats until no more are added so that implications can be chained
+    loop {
+      let mut added: bool = false;
+
+      for parse_relation in self.parse_relations {
+        let ParseRelation::Implies {
+          id,
+          implied_id,
+          implied_value,
+        } = parse_relation
+        else {
+          continue;
+        };
+
+        if find_first(parse_outputs, implied_id).is_some() {
+          continue;
+        }
+
+        let Some(implying) = find_first(parse_outputs, id) else {
+          continue;
+        };
+
+        let name: String = self.make_hyphenated_name(implied_id);
+
+        let implied_parse_output: ParseOutput = ParseOutput {
+          error: None,
+          found: ParseFound::Implied {
+            arg_index: implying.found.get_arg_index(),
+            implied_by: id.to_string(),
+            name,
+          },
+          known: Some(implied_id.to_string()),
+          suggestions: Vec::new(),
+          value: implied_value.map(|value: &str| value.to_string()),
+        };
+
+        parse_outputs.push(implied_parse_output);
+
+        added = true;
+      }
+
+      if !added {
+        return;
+      }
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Panics if validate finds issues other than warnings
+  //----------------------------------------------------------------------------
+  #[cfg(debug_assertions)]
+  fn assert_valid(&self) {
+    let errors: Vec<ParseConfigIssue> = self
+      .validate()
+      .into_iter()
+      .filter(|issue: &ParseConfigIssue| !issue.is_warning())
+      .collect();
+
+    assert!(
+      errors.is_empty(),
+      "Invalid option configurations: {errors:?}"
+    );
+  }
+
+  fn apply_duplicate_policies(
+    &self,
+    parse_outputs: &mut Vec<ParseOutput>,
+  ) {
+    for parse_option_config in self.parse_option_configs {
+      let id: &str = parse_option_config.id;
+
+      let indices: Vec<usize> = parse_outputs
+        .iter()
+        .enumerate()
+        .filter(|(_, parse_output): &(usize, &ParseOutput)| {
+          parse_output.error.is_none()
+            && parse_output.known.as_deref() == Some(id)
+        })
+        .map(|(index, _): (usize, &ParseOutput)| index)
+        .collect();
+
+      if indices.len() < 2 {
+        continue;
+      }
+
+      match parse_option_config.duplicate_policy {
+        DuplicatePolicy::Accumulate => {},
+        DuplicatePolicy::Error => {
+          let first_arg_index: usize =
+            parse_outputs[indices[0]].found.get_arg_index();
+
+          for index in &indices[1..] {
+            parse_outputs[*index].error = Some(ParseError::DuplicateOption {
+              first_arg_index,
+            });
+          }
+        },
+        DuplicatePolicy::FirstWins => {
+          for index in indices[1..].iter().rev() {
+            parse_outputs.remove(*index);
+          }
+        },
+        DuplicatePolicy::LastWins => {
+          for index in indices[..indices.len() - 1].iter().rev() {
+            parse_outputs.remove(*index);
+          }
+        },
+      }
+    }
+  }
+
+  fn check_deprecated(
+    &self,
+    parse_outputs: &[ParseOutput],
+  ) -> Vec<ParseWarning> {
+    let mut warnings: Vec<ParseWarning> = Vec::new();
+
+    for parse_output in parse_outputs {
+      let Some(known) = &parse_output.known else {
+        continue;
+      };
+
+      let parse_option_config_option: Option<&&ParseOptionConfig> = self
+        .parse_option_configs
+        .iter()
+        .find(|parse_option_config: &&&ParseOptionConfig| {
+          parse_option_config.id == known
+        });
+
+      let Some(parse_option_config) = parse_option_config_option else {
+        continue;
+      };
+
+      if !parse_option_config.deprecated {
+        continue;
+      }
+
+      warnings.push(ParseWarning::Deprecated {
+        found: parse_output.found.clone(),
+        id: known.clone(),
+        replacement: parse_option_config
+          .replacement
+          .map(|replacement: &str| replacement.to_string()),
+      });
+    }
+
+    warnings
+  }
+
+  fn check_groups(
+    &self,
+    parse_outputs: &[ParseOutput],
+  ) -> Vec<ParseRuleError> {
+    let mut rule_errors: Vec<ParseRuleError> = Vec::new();
+
+    for parse_group in self.parse_groups {
+      let founds: Vec<ParseFound> = parse_group
+        .ids
+        .iter()
+        .filter_map(|id: &&str| find_first(parse_outputs, id))
+        .map(|parse_output: &ParseOutput| parse_output.found.clone())
+        .collect();
+
+      let too_few: bool =
+        founds.is_empty() && !parse_group.group_usage.allows_none();
+
+      let too_many: bool =
+        founds.len() > 1 && !parse_group.group_usage.allows_multiple();
+
+      if !too_few && !too_many {
+        continue;
+      }
+
+      let names: Vec<String> = parse_group
+        .ids
+        .iter()
+        .map(|id: &&str| self.make_hyphenated_name(id))
+        .collect();
+
+      let group_id: String = parse_group.id.to_string();
+
+      if too_few {
+        rule_errors.push(ParseRuleError::GroupMissing {
+          group_id,
+          names,
+        });
+      } else {
+        rule_errors.push(ParseRuleError::GroupMultiple {
+          founds,
+          group_id,
+          names,
+        });
+      }
+    }
+
+    rule_errors
+  }
+
+  fn check_relations(
+    &self,
+    parse_outputs: &[ParseOutput],
+  ) -> Vec<ParseRuleError> {
+    let mut rule_errors: Vec<ParseRuleError> = Vec::new();
+
+    for parse_relation in self.parse_relations {
+      match parse_relation {
+        ParseRelation::Conflicts {
+          conflicting_id,
+          id,
+        } => {
+          let (Some(parse_output), Some(conflicting_parse_output)) = (
+            find_first(parse_outputs, id),
+            find_first(parse_outputs, conflicting_id),
+          ) else {
+            continue;
+          };
+
+          rule_errors.push(ParseRuleError::Conflict {
+            conflicting_found: conflicting_parse_output.found.clone(),
+            conflicting_id: conflicting_id.to_string(),
+            found: parse_output.found.clone(),
+            id: id.to_string(),
+          });
+        },
+        ParseRelation::Implies {
+          ..
+        } => {},
+        ParseRelation::Requires {
+          id,
+          required_id,
+        } => {
+          let Some(parse_output) = find_first(parse_outputs, id) else {
+            continue;
+          };
+
+          if find_first(parse_outputs, required_id).is_some() {
+            continue;
+          }
+
+          let required_name: String = self.make_hyphenated_name(required_id);
+
+          rule_errors.push(ParseRuleError::RequirementMissing {
+            found: parse_output.found.clone(),
+            id: id.to_string(),
+            required_id: required_id.to_string(),
+            required_name,
+          });
+        },
+      }
+    }
+
+    rule_errors
+  }
+
+  fn check_required(
+    &self,
+    parse_outputs: &[ParseOutput],
+  ) -> Option<ParseRuleError> {
+    let mut ids: Vec<String> = Vec::new();
+
+    let mut names: Vec<String> = Vec::new();
+
+    for parse_option_config in self.parse_option_configs {
+      if !parse_option_config.required {
+        continue;
+      }
+
+      if find_first(parse_outputs, parse_option_config.id).is_none() {
+        ids.push(parse_option_config.id.to_string());
+
+        names.push(parse_option_config.name.make_hyphenated_name());
+      }
+    }
+
+    if ids.is_empty() {
+      return None;
+    }
+
+    Some(ParseRuleError::MissingOptions {
+      ids,
+      names,
+    })
+  }
+
+  //----------------------------------------------------------------------------
+  /// The hyphenated name of the option with the id, or the id if not known
+  //----------------------------------------------------------------------------
+  fn make_hyphenated_name(
+    &self,
+    id: &str,
+  ) -> String {
+    self
+      .parse_option_configs
+      .iter()
+      .find(|parse_option_config: &&&ParseOptionConfig| {
+        parse_option_config.id == id
+      })
+      .map(|parse_option_config: &&ParseOptionConfig| {
+        parse_option_config.name.make_hyphenated_name()
+      })
+      .unwrap_or(id.to_string())
+  }
 }
 
 impl<'a> IntoIterator for &ParseInput<'a> {
@@ -35,8 +595,43 @@ impl<'a> IntoIterator for &ParseInput<'a> {
     ParseIterator {
       args: self.args,
       parse_option_configs: self.parse_option_configs,
-      skip_arg: 0,
+      skip_arg: self.skip_arg,
       skip_char: 0,
     }
   }
 }
+
+//------------------------------------------------------------------------------
+/// Reports a name containing "=" or starting with "-"
+//------------------------------------------------------------------------------
+fn check_name(
+  issues: &mut Vec<ParseConfigIssue>,
+  id: &str,
+  name: &str,
+) {
+  if name.contains('=') {
+    issues.push(ParseConfigIssue::NameContainsEquals {
+      id: id.to_string(),
+      name: name.to_string(),
+    });
+  }
+
+  if name.starts_with('-') {
+    issues.push(ParseConfigIssue::NameStartsWithHyphen {
+      id: id.to_string(),
+      name: name.to_string(),
+    });
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Returns the first option found with the option configuration id
+//------------------------------------------------------------------------------
+fn find_first<'b>(
+  parse_outputs: &'b [ParseOutput],
+  id: &str,
+) -> Option<&'b ParseOutput> {
+  parse_outputs.iter().find(|parse_output: &&ParseOutput| {
+    parse_output.known.as_deref() == Some(id)
+  })
+}
@@ -2,10 +2,10 @@
 //! Unit tests for ParseInput.
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-08-04
-//! - Updated: 2024-08-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
@@ -14,7 +14,13 @@
 use std::string::ToString;
 use std::sync::LazyLock;
 
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::group_usage::GroupUsage;
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
 use crate::parse::parse_option_name::ParseOptionName;
+use crate::parse::parse_relation::ParseRelation;
+use crate::parse::parse_warning::ParseWarning;
 use crate::parse::value_usage::ValueUsage;
 
 use super::*;
@@ -31,21 +37,59 @@ static TEST_ARGS_0: LazyLock<Vec<String>> = LazyLock::new(|| {
 const TEST_ID_0: &str = "TEST_ID_0";
 
 const TEST_PARSE_OPTION_CONFIG_0: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_0,
   name: ParseOptionName::Both {
     name_long: "TEST",
     name_short: 'T',
   },
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Optional,
 };
 
+const TEST_PARSE_OPTION_CONFIG_REQUIRED_0: ParseOptionConfig =
+  ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
+    id: "TEST_ID_REQUIRED_0",
+    name: ParseOptionName::Long("REQUIRED0"),
+    replacement: None,
+    required: true,
+    value_usage: ValueUsage::Optional,
+  };
+
+const TEST_PARSE_OPTION_CONFIG_REQUIRED_1: ParseOptionConfig =
+  ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
+    id: "TEST_ID_REQUIRED_1",
+    name: ParseOptionName::Short('R'),
+    replacement: None,
+    required: true,
+    value_usage: ValueUsage::Optional,
+  };
+
 const TEST_PARSE_OPTION_CONFIGS_0: &[&ParseOptionConfig] =
   &[&TEST_PARSE_OPTION_CONFIG_0];
 
+const TEST_PARSE_OPTION_CONFIGS_REQUIRED: &[&ParseOptionConfig] = &[
+  &TEST_PARSE_OPTION_CONFIG_0,
+  &TEST_PARSE_OPTION_CONFIG_REQUIRED_0,
+  &TEST_PARSE_OPTION_CONFIG_REQUIRED_1,
+];
+
 static TEST_PARSE_INPUT_0: LazyLock<ParseInput> =
   LazyLock::new(|| ParseInput {
     args: &TEST_ARGS_0,
+    parse_groups: &[],
     parse_option_configs: TEST_PARSE_OPTION_CONFIGS_0,
+    parse_relations: &[],
+    skip_arg: 0,
   });
 
 #[test]
@@ -61,3 +105,876 @@ fn test0() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_from_args_without_program_name_0() {
+  let expected: ParseInput = ParseInput {
+    args: &TEST_ARGS_0,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_REQUIRED,
+    parse_relations: &[],
+    skip_arg: 0,
+  };
+
+  let actual: ParseInput = ParseInput::from_args_without_program_name(
+    &TEST_ARGS_0,
+    TEST_PARSE_OPTION_CONFIGS_REQUIRED,
+  );
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_new_0() {
+  let parse_relations: &[ParseRelation] = &[
+    ParseRelation::Requires {
+      id: TEST_ID_0,
+      required_id: "TEST_ID_REQUIRED_0",
+    },
+  ];
+
+  let expected: ParseInput = ParseInput {
+    args: &TEST_ARGS_0,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_REQUIRED,
+    parse_relations,
+    skip_arg: 1,
+  };
+
+  let actual: ParseInput =
+    ParseInput::new(&TEST_ARGS_0, TEST_PARSE_OPTION_CONFIGS_REQUIRED)
+      .with_relations(parse_relations);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_0() {
+  let test_parse_input: ParseInput = ParseInput {
+    args: &TEST_ARGS_0,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_REQUIRED,
+    parse_relations: &[],
+    skip_arg: 0,
+  };
+
+  let expected: Vec<ParseRuleError> = vec![
+    ParseRuleError::MissingOptions {
+      ids: vec![
+        "TEST_ID_REQUIRED_0".to_string(),
+        "TEST_ID_REQUIRED_1".to_string(),
+      ],
+      names: vec![
+        "--REQUIRED0".to_string(),
+        "-R".to_string(),
+      ],
+    },
+  ];
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual.parse_outputs.len(), 2);
+
+  assert_eq!(actual.rule_errors, expected);
+}
+
+#[test]
+fn test_parse_1() {
+  let test_args: Vec<String> = vec![
+    "--REQUIRED0".to_string(),
+    "-R".to_string(),
+  ];
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_REQUIRED,
+    parse_relations: &[],
+    skip_arg: 0,
+  };
+
+  let expected: ParseResults = ParseResults {
+    parse_outputs: vec![
+      ParseOutput {
+        error: None,
+        found: ParseFound::Long {
+          arg_index: 0,
+          name_long: "REQUIRED0".to_string(),
+        },
+        known: Some("TEST_ID_REQUIRED_0".to_string()),
+        suggestions: Vec::new(),
+        value: None,
+      },
+      ParseOutput {
+        error: None,
+        found: ParseFound::Short {
+          arg_index: 1,
+          char_index: 0,
+          name_short: 'R',
+        },
+        known: Some("TEST_ID_REQUIRED_1".to_string()),
+        suggestions: Vec::new(),
+        value: None,
+      },
+    ],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual, expected);
+}
+
+//------------------------------------------------------------------------------
+// parse() relations unit tests
+//------------------------------------------------------------------------------
+
+const TEST_PARSE_OPTION_CONFIG_A: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_A",
+  name: ParseOptionName::Both {
+    name_long: "ALPHA",
+    name_short: 'A',
+  },
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Verboten,
+};
+
+const TEST_PARSE_OPTION_CONFIG_B: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_B",
+  name: ParseOptionName::Both {
+    name_long: "BRAVO",
+    name_short: 'B',
+  },
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Optional,
+};
+
+const TEST_PARSE_OPTION_CONFIG_C: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_C",
+  name: ParseOptionName::Long("CHARLIE"),
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Optional,
+};
+
+const TEST_PARSE_OPTION_CONFIGS_RELATIONS: &[&ParseOptionConfig] = &[
+  &TEST_PARSE_OPTION_CONFIG_A,
+  &TEST_PARSE_OPTION_CONFIG_B,
+  &TEST_PARSE_OPTION_CONFIG_C,
+];
+
+fn make_args(args: &[&str]) -> Vec<String> {
+  args.iter().map(|arg: &&str| arg.to_string()).collect()
+}
+
+#[test]
+fn test_parse_conflicts_0() {
+  let test_args: Vec<String> = make_args(&[
+    "-A", "--BRAVO",
+  ]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Conflicts {
+        conflicting_id: "TEST_ID_B",
+        id: "TEST_ID_A",
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let expected: Vec<ParseRuleError> = vec![
+    ParseRuleError::Conflict {
+      conflicting_found: ParseFound::Long {
+        arg_index: 1,
+        name_long: "BRAVO".to_string(),
+      },
+      conflicting_id: "TEST_ID_B".to_string(),
+      found: ParseFound::Short {
+        arg_index: 0,
+        char_index: 0,
+        name_short: 'A',
+      },
+      id: "TEST_ID_A".to_string(),
+    },
+  ];
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual.rule_errors, expected);
+}
+
+#[test]
+fn test_parse_conflicts_1() {
+  let test_args: Vec<String> = make_args(&["-A"]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Conflicts {
+        conflicting_id: "TEST_ID_B",
+        id: "TEST_ID_A",
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert!(actual.rule_errors.is_empty());
+}
+
+#[test]
+fn test_parse_implies_0() {
+  let test_args: Vec<String> = make_args(&["-A"]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Implies {
+        id: "TEST_ID_A",
+        implied_id: "TEST_ID_B",
+        implied_value: Some("false"),
+      },
+      ParseRelation::Implies {
+        id: "TEST_ID_B",
+        implied_id: "TEST_ID_C",
+        implied_value: None,
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let expected: Vec<ParseOutput> = vec![
+    ParseOutput {
+      error: None,
+      found: ParseFound::Short {
+        arg_index: 0,
+        char_index: 0,
+        name_short: 'A',
+      },
+      known: Some("TEST_ID_A".to_string()),
+      suggestions: Vec::new(),
+      value: None,
+    },
+    ParseOutput {
+      error: None,
+      found: ParseFound::Implied {
+        arg_index: 0,
+        implied_by: "TEST_ID_A".to_string(),
+        name: "--BRAVO".to_string(),
+      },
+      known: Some("TEST_ID_B".to_string()),
+      suggestions: Vec::new(),
+      value: Some("false".to_string()),
+    },
+    ParseOutput {
+      error: None,
+      found: ParseFound::Implied {
+        arg_index: 0,
+        implied_by: "TEST_ID_B".to_string(),
+        name: "--CHARLIE".to_string(),
+      },
+      known: Some("TEST_ID_C".to_string()),
+      suggestions: Vec::new(),
+      value: None,
+    },
+  ];
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual.parse_outputs, expected);
+}
+
+#[test]
+fn test_parse_implies_1() {
+  let test_args: Vec<String> = make_args(&[
+    "-A", "-B=true",
+  ]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Implies {
+        id: "TEST_ID_A",
+        implied_id: "TEST_ID_B",
+        implied_value: Some("false"),
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual.parse_outputs.len(), 2);
+
+  assert_eq!(
+    actual.get_last("TEST_ID_B").unwrap().value,
+    Some("true".to_string())
+  );
+}
+
+#[test]
+fn test_parse_requires_0() {
+  let test_args: Vec<String> = make_args(&["--ALPHA"]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Requires {
+        id: "TEST_ID_A",
+        required_id: "TEST_ID_C",
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let expected: Vec<ParseRuleError> = vec![
+    ParseRuleError::RequirementMissing {
+      found: ParseFound::Long {
+        arg_index: 0,
+        name_long: "ALPHA".to_string(),
+      },
+      id: "TEST_ID_A".to_string(),
+      required_id: "TEST_ID_C".to_string(),
+      required_name: "--CHARLIE".to_string(),
+    },
+  ];
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert_eq!(actual.rule_errors, expected);
+}
+
+#[test]
+fn test_parse_requires_1() {
+  let test_args: Vec<String> = make_args(&[
+    "--ALPHA",
+    "--CHARLIE",
+  ]);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Requires {
+        id: "TEST_ID_A",
+        required_id: "TEST_ID_C",
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let actual: ParseResults = test_parse_input.parse();
+
+  assert!(actual.rule_errors.is_empty());
+}
+
+//------------------------------------------------------------------------------
+// parse() groups unit tests
+//------------------------------------------------------------------------------
+
+const TEST_PARSE_GROUP_ID: &str = "TEST_GROUP_ID";
+
+const TEST_PARSE_GROUP_IDS: &[&str] = &[
+  "TEST_ID_A",
+  "TEST_ID_B",
+  "TEST_ID_C",
+];
+
+fn parse_group(
+  args: &[&str],
+  group_usage: GroupUsage,
+) -> Vec<ParseRuleError> {
+  let test_args: Vec<String> = make_args(args);
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[
+      ParseGroup {
+        group_usage,
+        id: TEST_PARSE_GROUP_ID,
+        ids: TEST_PARSE_GROUP_IDS,
+      },
+    ],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[],
+    skip_arg: 0,
+  };
+
+  test_parse_input.parse().rule_errors
+}
+
+#[test]
+fn test_parse_group_at_least_one_0() {
+  let expected: Vec<ParseRuleError> = vec![
+    ParseRuleError::GroupMissing {
+      group_id: TEST_PARSE_GROUP_ID.to_string(),
+      names: vec![
+        "--ALPHA".to_string(),
+        "--BRAVO".to_string(),
+        "--CHARLIE".to_string(),
+      ],
+    },
+  ];
+
+  let actual: Vec<ParseRuleError> = parse_group(&[], GroupUsage::AtLeastOne);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_group_at_least_one_1() {
+  let actual: Vec<ParseRuleError> = parse_group(
+    &[
+      "-A", "-B",
+    ],
+    GroupUsage::AtLeastOne,
+  );
+
+  assert!(actual.is_empty());
+}
+
+#[test]
+fn test_parse_group_at_most_one_0() {
+  let actual: Vec<ParseRuleError> = parse_group(&[], GroupUsage::AtMostOne);
+
+  assert!(actual.is_empty());
+}
+
+#[test]
+fn test_parse_group_at_most_one_1() {
+  let expected: Vec<ParseRuleError> = vec![
+    ParseRuleError::GroupMultiple {
+      founds: vec![
+        ParseFound::Short {
+          arg_index: 0,
+          char_index: 0,
+          name_short: 'A',
+        },
+        ParseFound::Long {
+          arg_index: 1,
+          name_long: "CHARLIE".to_string(),
+        },
+      ],
+      group_id: TEST_PARSE_GROUP_ID.to_string(),
+      names: vec![
+        "--ALPHA".to_string(),
+        "--BRAVO".to_string(),
+        "--CHARLIE".to_string(),
+      ],
+    },
+  ];
+
+  let actual: Vec<ParseRuleError> = parse_group(
+    &[
+      "-A",
+      "--CHARLIE",
+    ],
+    GroupUsage::AtMostOne,
+  );
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_group_exactly_one_0() {
+  let actual: Vec<ParseRuleError> = parse_group(&[], GroupUsage::ExactlyOne);
+
+  assert_eq!(actual.len(), 1);
+}
+
+#[test]
+fn test_parse_group_exactly_one_1() {
+  let actual: Vec<ParseRuleError> =
+    parse_group(&["--BRAVO"], GroupUsage::ExactlyOne);
+
+  assert!(actual.is_empty());
+}
+
+#[test]
+fn test_parse_group_exactly_one_2() {
+  let actual: Vec<ParseRuleError> = parse_group(
+    &[
+      "-A", "-B",
+    ],
+    GroupUsage::ExactlyOne,
+  );
+
+  assert_eq!(actual.len(), 1);
+}
+
+//------------------------------------------------------------------------------
+// parse() duplicate policy unit tests
+//------------------------------------------------------------------------------
+
+fn parse_duplicates(duplicate_policy: DuplicatePolicy) -> Vec<ParseOutput> {
+  parse_duplicates_with_args(
+    duplicate_policy,
+    &[
+      "-D=1", "-D=2", "-D=3",
+    ],
+  )
+}
+
+fn parse_duplicates_with_args(
+  duplicate_policy: DuplicatePolicy,
+  args: &[&str],
+) -> Vec<ParseOutput> {
+  let test_args: Vec<String> = make_args(args);
+
+  let test_parse_option_config: ParseOptionConfig = ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy,
+    hidden: false,
+    id: "TEST_ID_D",
+    name: ParseOptionName::Short('D'),
+    replacement: None,
+    required: false,
+    value_usage: ValueUsage::Required,
+  };
+
+  let test_parse_input: ParseInput = ParseInput {
+    args: &test_args,
+    parse_groups: &[],
+    parse_option_configs: &[&test_parse_option_config],
+    parse_relations: &[],
+    skip_arg: 0,
+  };
+
+  test_parse_input.parse().parse_outputs
+}
+
+#[test]
+fn test_parse_duplicate_policy_accumulate_0() {
+  let actual: Vec<ParseOutput> = parse_duplicates(DuplicatePolicy::Accumulate);
+
+  assert_eq!(actual.len(), 3);
+
+  assert!(
+    actual
+      .iter()
+      .all(|parse_output: &ParseOutput| parse_output.error.is_none())
+  );
+}
+
+#[test]
+fn test_parse_duplicate_policy_error_0() {
+  let actual: Vec<Option<ParseError>> =
+    parse_duplicates(DuplicatePolicy::Error)
+      .into_iter()
+      .map(|parse_output: ParseOutput| parse_output.error)
+      .collect();
+
+  let expected: Vec<Option<ParseError>> = vec![
+    None,
+    Some(ParseError::DuplicateOption {
+      first_arg_index: 0,
+    }),
+    Some(ParseError::DuplicateOption {
+      first_arg_index: 0,
+    }),
+  ];
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_duplicate_policy_first_wins_0() {
+  let actual: Vec<Option<String>> =
+    parse_duplicates(DuplicatePolicy::FirstWins)
+      .into_iter()
+      .map(|parse_output: ParseOutput| parse_output.value)
+      .collect();
+
+  assert_eq!(actual, vec![Some("1".to_string())]);
+}
+
+#[test]
+fn test_parse_duplicate_policy_last_wins_0() {
+  let actual: Vec<Option<String>> = parse_duplicates(DuplicatePolicy::LastWins)
+    .into_iter()
+    .map(|parse_output: ParseOutput| parse_output.value)
+    .collect();
+
+  assert_eq!(actual, vec![Some("3".to_string())]);
+}
+
+#[test]
+fn test_parse_duplicate_policy_first_wins_1() {
+  let actual: Vec<ParseOutput> = parse_duplicates_with_args(
+    DuplicatePolicy::FirstWins,
+    &[
+      "-D=", "-D=2",
+    ],
+  );
+
+  assert_eq!(actual.len(), 2);
+
+  assert_eq!(actual[0].error, Some(ParseError::ValueMissingAfterEquals));
+
+  assert_eq!(actual[1].value, Some("2".to_string()));
+}
+
+#[test]
+fn test_parse_duplicate_policy_last_wins_1() {
+  let actual: Vec<ParseOutput> = parse_duplicates_with_args(
+    DuplicatePolicy::LastWins,
+    &[
+      "-D=1", "-D=",
+    ],
+  );
+
+  assert_eq!(actual.len(), 2);
+
+  assert_eq!(actual[0].value, Some("1".to_string()));
+
+  assert_eq!(actual[1].error, Some(ParseError::ValueMissingAfterEquals));
+}
+
+#[test]
+fn test_parse_deprecated_0() {
+  let test_parse_option_configs: &[&ParseOptionConfig] = &[
+    &ParseOptionConfig::long("out")
+      .required_value()
+      .replaced_by("--output"),
+    &ParseOptionConfig::long("old").short('o').deprecated(),
+    &ParseOptionConfig::long("output").required_value(),
+  ];
+
+  let test_args: Vec<String> = vec![
+    "--out=a".to_string(),
+    "-o".to_string(),
+    "--output=b".to_string(),
+  ];
+
+  let actual: ParseResults = ParseInput::from_args_without_program_name(
+    &test_args,
+    test_parse_option_configs,
+  )
+  .parse();
+
+  let expected: Vec<ParseWarning> = vec![
+    ParseWarning::Deprecated {
+      found: ParseFound::Long {
+        arg_index: 0,
+        name_long: "out".to_string(),
+      },
+      id: "out".to_string(),
+      replacement: Some("--output".to_string()),
+    },
+    ParseWarning::Deprecated {
+      found: ParseFound::Short {
+        arg_index: 1,
+        char_index: 0,
+        name_short: 'o',
+      },
+      id: "old".to_string(),
+      replacement: None,
+    },
+  ];
+
+  assert_eq!(actual.warnings, expected);
+
+  assert!(!actual.has_errors());
+}
+
+//------------------------------------------------------------------------------
+// validate() unit tests
+//------------------------------------------------------------------------------
+
+fn validate(
+  parse_option_configs: &[&ParseOptionConfig]
+) -> Vec<ParseConfigIssue> {
+  ParseInput {
+    args: &[],
+    parse_groups: &[],
+    parse_option_configs,
+    parse_relations: &[],
+    skip_arg: 0,
+  }
+  .validate()
+}
+
+#[test]
+fn test_validate_0() {
+  assert_eq!(validate(TEST_PARSE_OPTION_CONFIGS_RELATIONS), Vec::new());
+}
+
+#[test]
+fn test_validate_1() {
+  let duplicate_config: ParseOptionConfig = ParseOptionConfig {
+    name: ParseOptionName::Both {
+      name_long: "ALPHA",
+      name_short: 'A',
+    },
+    ..TEST_PARSE_OPTION_CONFIG_A
+  };
+
+  let expected: Vec<ParseConfigIssue> = vec![
+    ParseConfigIssue::DuplicateId {
+      id: "TEST_ID_A".to_string(),
+    },
+    ParseConfigIssue::DuplicateNameLong {
+      first_id: "TEST_ID_A".to_string(),
+      id: "TEST_ID_A".to_string(),
+      name_long: "ALPHA".to_string(),
+    },
+    ParseConfigIssue::DuplicateNameShort {
+      first_id: "TEST_ID_A".to_string(),
+      id: "TEST_ID_A".to_string(),
+      name_short: 'A',
+    },
+  ];
+
+  let actual: Vec<ParseConfigIssue> = validate(&[
+    &TEST_PARSE_OPTION_CONFIG_A,
+    &duplicate_config,
+  ]);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_validate_2() {
+  let test_config_0: ParseOptionConfig = ParseOptionConfig {
+    id: "",
+    name: ParseOptionName::Long(""),
+    ..TEST_PARSE_OPTION_CONFIG_C
+  };
+
+  let test_config_1: ParseOptionConfig = ParseOptionConfig {
+    name: ParseOptionName::Both {
+      name_long: "-x=y",
+      name_short: '1',
+    },
+    ..TEST_PARSE_OPTION_CONFIG_B
+  };
+
+  let expected: Vec<ParseConfigIssue> = vec![
+    ParseConfigIssue::EmptyId,
+    ParseConfigIssue::EmptyNameLong {
+      id: "".to_string(),
+    },
+    ParseConfigIssue::NameContainsEquals {
+      id: "TEST_ID_B".to_string(),
+      name: "-x=y".to_string(),
+    },
+    ParseConfigIssue::NameStartsWithHyphen {
+      id: "TEST_ID_B".to_string(),
+      name: "-x=y".to_string(),
+    },
+    ParseConfigIssue::DigitNameShort {
+      id: "TEST_ID_B".to_string(),
+      name_short: '1',
+    },
+  ];
+
+  let actual: Vec<ParseConfigIssue> = validate(&[
+    &test_config_0,
+    &test_config_1,
+  ]);
+
+  assert_eq!(actual, expected);
+
+  assert!(actual[4].is_warning());
+}
+
+#[test]
+fn test_validate_3() {
+  let parse_input: ParseInput = ParseInput {
+    args: &[],
+    parse_groups: &[
+      ParseGroup {
+        group_usage: GroupUsage::ExactlyOne,
+        id: TEST_PARSE_GROUP_ID,
+        ids: &[
+          "TEST_ID_A",
+          "TEST_ID_X",
+        ],
+      },
+    ],
+    parse_option_configs: TEST_PARSE_OPTION_CONFIGS_RELATIONS,
+    parse_relations: &[
+      ParseRelation::Requires {
+        id: "TEST_ID_X",
+        required_id: "TEST_ID_B",
+      },
+    ],
+    skip_arg: 0,
+  };
+
+  let expected: Vec<ParseConfigIssue> = vec![
+    ParseConfigIssue::UnknownId {
+      id: "TEST_ID_X".to_string(),
+    },
+  ];
+
+  assert_eq!(parse_input.validate(), expected);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn test_validate_4() {
+  let duplicate_config: ParseOptionConfig = ParseOptionConfig {
+    id: "TEST_ID_D",
+    ..TEST_PARSE_OPTION_CONFIG_A
+  };
+
+  let parse_option_configs: &[&ParseOptionConfig] = &[
+    &TEST_PARSE_OPTION_CONFIG_A,
+    &duplicate_config,
+  ];
+
+  ParseInput {
+    args: &[],
+    parse_groups: &[],
+    parse_option_configs,
+    parse_relations: &[],
+    skip_arg: 0,
+  }
+  .parse();
+}
+
+#[test]
+fn test_validate_5() {
+  let test_config: ParseOptionConfig =
+    TEST_PARSE_OPTION_CONFIG_A.hidden().required();
+
+  let expected: Vec<ParseConfigIssue> = vec![
+    ParseConfigIssue::HiddenRequired {
+      id: "TEST_ID_A".to_string(),
+    },
+  ];
+
+  let actual: Vec<ParseConfigIssue> = validate(&[&test_config]);
+
+  assert_eq!(actual, expected);
+
+  assert!(actual[0].is_warning());
+}
@@ -5,7 +5,7 @@
 //! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-27
-//! - Updated: 2026-04-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
@@ -15,6 +15,7 @@ use crate::parse::hyphenation_type::HyphenationType;
 use crate::parse::parse_error::ParseError;
 use crate::parse::parse_found::ParseFound;
 use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_suggestion;
 use crate::parse::value_usage::ValueUsage;
 
 use super::parse_option_config::ParseOptionConfig;
@@ -38,6 +39,23 @@ pub struct ParseIterator<'a> {
 }
 
 impl<'a> ParseIterator<'a> {
+  //----------------------------------------------------------------------------
+  /// The command-line arguments without the program name and known options
+  ///
+  /// - Use when the arguments do not start with the program name
+  //----------------------------------------------------------------------------
+  pub const fn from_args_without_program_name(
+    args: &'a [String],
+    parse_option_configs: &'a [&'a ParseOptionConfig<'a>],
+  ) -> Self {
+    Self {
+      args,
+      parse_option_configs,
+      skip_arg: 0,
+      skip_char: 0,
+    }
+  }
+
   //----------------------------------------------------------------------------
   /// A slice of the command-line arguments with skips of zero
   //----------------------------------------------------------------------------
@@ -50,6 +68,24 @@ impl<'a> ParseIterator<'a> {
     }
   }
 
+  //----------------------------------------------------------------------------
+  /// The command-line arguments and known options
+  ///
+  /// - Skips the first argument, the program name, as from env::args()
+  /// - The argument indices in the outputs still count the program name
+  //----------------------------------------------------------------------------
+  pub const fn new(
+    args: &'a [String],
+    parse_option_configs: &'a [&'a ParseOptionConfig<'a>],
+  ) -> Self {
+    Self {
+      args,
+      parse_option_configs,
+      skip_arg: 1,
+      skip_char: 0,
+    }
+  }
+
   //----------------------------------------------------------------------------
   /// Returns a list of options with errors from the command-line arguments
   //----------------------------------------------------------------------------
@@ -132,13 +168,19 @@ impl<'a> ParseIterator<'a> {
       }
     }
 
+    let found: ParseFound = ParseFound::Long {
+      arg_index,
+      name_long,
+    };
+
+    let suggestions: Vec<String> =
+      parse_suggestion::make_suggestions(&found, self.parse_option_configs);
+
     ParseOutput {
       error,
-      found: ParseFound::Long {
-        arg_index,
-        name_long,
-      },
+      found,
       known: None,
+      suggestions,
       value,
     }
   }
@@ -202,6 +244,7 @@ impl<'a> ParseIterator<'a> {
       error: error_option,
       found: parse_found,
       known: Some(parse_option_config.id.to_string()),
+      suggestions: Vec::new(),
       value: value_option,
     })
   }
@@ -295,14 +338,20 @@ impl<'a> ParseIterator<'a> {
     let value: Option<String> =
       value_option.map(|value_str: &str| value_str.to_string());
 
+    let found: ParseFound = ParseFound::Short {
+      arg_index,
+      char_index: skip_char,
+      name_short: c,
+    };
+
+    let suggestions: Vec<String> =
+      parse_suggestion::make_suggestions(&found, self.parse_option_configs);
+
     Some(ParseOutput {
       error,
-      found: ParseFound::Short {
-        arg_index,
-        char_index: skip_char,
-        name_short: c,
-      },
+      found,
       known: None,
+      suggestions,
       value,
     })
   }
@@ -364,6 +413,7 @@ impl<'a> ParseIterator<'a> {
       error,
       found,
       known: Some(parse_option_config.id.to_string()),
+      suggestions: Vec::new(),
       value,
     })
   }
@@ -378,6 +428,9 @@ impl Iterator for ParseIterator<'_> {
     let parse_output: ParseOutput = parse_output_option?;
 
     match &parse_output.found {
+      ParseFound::Implied {
+        ..
+      } => {},
       ParseFound::Long {
         arg_index,
         ..
@@ -2,10 +2,10 @@
 //! Unit tests for module parse_iterator
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-31
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,6 +14,7 @@
 use std::string::ToString;
 use std::sync::LazyLock;
 
+use crate::parse::duplicate_policy::DuplicatePolicy;
 use crate::parse::parse_error::ParseError;
 use crate::parse::parse_option_name::ParseOptionName;
 use crate::parse::value_usage::ValueUsage;
@@ -35,29 +36,49 @@ const TEST_ID_2: &str = "TEST_ID_2";
 const TEST_ID_3: &str = "TEST_ID_3";
 
 const TEST_PARSE_OPTION_CONFIG_0: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_0,
   name: ParseOptionName::Both {
     name_long: "TEST",
     name_short: 'T',
   },
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Optional,
 };
 
 const TEST_PARSE_OPTION_CONFIG_1: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_1,
   name: ParseOptionName::Long(""),
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Verboten,
 };
 
 const TEST_PARSE_OPTION_CONFIG_LONG: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_2,
   name: ParseOptionName::Long("TEST"),
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Optional,
 };
 
 const TEST_PARSE_OPTION_CONFIG_SHORT: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_3,
   name: ParseOptionName::Short('T'),
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Optional,
 };
 
@@ -89,6 +110,7 @@ fn test_collect_0() {
         name_long: "TEST".to_string(),
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: None,
     },
     ParseOutput {
@@ -99,6 +121,7 @@ fn test_collect_0() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: None,
     },
   ];
@@ -108,6 +131,26 @@ fn test_collect_0() {
   assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_from_args_without_program_name_0() {
+  let parse_option_configs: &[&ParseOptionConfig] =
+    &[&TEST_PARSE_OPTION_CONFIG_0];
+
+  let expected: ParseIterator = ParseIterator {
+    args: &TEST_ARGS_0,
+    parse_option_configs,
+    skip_arg: 0,
+    skip_char: 0,
+  };
+
+  let actual: ParseIterator = ParseIterator::from_args_without_program_name(
+    &TEST_ARGS_0,
+    parse_option_configs,
+  );
+
+  assert_eq!(actual, expected);
+}
+
 #[test]
 fn test_from_slice_0() {
   let test_args_slice: Vec<String> = vec!["TEST".to_string()];
@@ -126,6 +169,24 @@ fn test_from_slice_0() {
   assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_new_0() {
+  let parse_option_configs: &[&ParseOptionConfig] =
+    &[&TEST_PARSE_OPTION_CONFIG_0];
+
+  let expected: ParseIterator = ParseIterator {
+    args: &TEST_ARGS_0,
+    parse_option_configs,
+    skip_arg: 1,
+    skip_char: 0,
+  };
+
+  let actual: ParseIterator =
+    ParseIterator::new(&TEST_ARGS_0, parse_option_configs);
+
+  assert_eq!(actual, expected);
+}
+
 //------------------------------------------------------------------------------
 // make_hyphenated_option_name() unit tests
 //------------------------------------------------------------------------------
@@ -195,6 +256,7 @@ fn test_next_0() {
       name_short: 'T',
     },
     known: Some("TEST_ID_0".to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -205,6 +267,7 @@ fn test_next_0() {
       name_long: "TEST".to_string(),
     },
     known: Some("TEST_ID_0".to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -240,6 +303,7 @@ fn test_next_1() {
       name_short: 'T',
     },
     known: Some("TEST_ID_0".to_string()),
+    suggestions: Vec::new(),
     value: None,
   };
 
@@ -250,6 +314,7 @@ fn test_next_1() {
       name_long: "TEST".to_string(),
     },
     known: Some("TEST_ID_0".to_string()),
+    suggestions: Vec::new(),
     value: None,
   };
 
@@ -290,6 +355,7 @@ fn test_parse_next_0() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -322,6 +388,7 @@ fn test_parse_next_1() {
       name_long: "UNKNOWN".to_string(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -354,6 +421,7 @@ fn test_parse_next_2() {
       name_long: "UNKNOWN".to_string(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: Some("TEST_VALUE".to_string()),
   });
 
@@ -386,6 +454,68 @@ fn test_parse_next_3() {
       name_long: "UNKNOWN".to_string(),
     },
     known: None,
+    suggestions: Vec::new(),
+    value: None,
+  });
+
+  let actual: Option<ParseOutput> = test_parse_iterator.parse_next();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_next_suggestions_0() {
+  let test_args: Vec<String> = vec!["--TETS".to_string()];
+
+  let test_known_options: Vec<&ParseOptionConfig> =
+    vec![&TEST_PARSE_OPTION_CONFIG_0];
+
+  let test_parse_iterator: ParseIterator = ParseIterator {
+    args: &test_args,
+    parse_option_configs: &test_known_options,
+    skip_arg: 0,
+    skip_char: 0,
+  };
+
+  let expected: Option<ParseOutput> = Some(ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 0,
+      name_long: "TETS".to_string(),
+    },
+    known: None,
+    suggestions: vec!["--TEST".to_string()],
+    value: None,
+  });
+
+  let actual: Option<ParseOutput> = test_parse_iterator.parse_next();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_next_suggestions_1() {
+  let test_args: Vec<String> = vec!["-t".to_string()];
+
+  let test_known_options: Vec<&ParseOptionConfig> =
+    vec![&TEST_PARSE_OPTION_CONFIG_0];
+
+  let test_parse_iterator: ParseIterator = ParseIterator {
+    args: &test_args,
+    parse_option_configs: &test_known_options,
+    skip_arg: 0,
+    skip_char: 0,
+  };
+
+  let expected: Option<ParseOutput> = Some(ParseOutput {
+    error: None,
+    found: ParseFound::Short {
+      arg_index: 0,
+      char_index: 0,
+      name_short: 't',
+    },
+    known: None,
+    suggestions: vec!["-T".to_string()],
     value: None,
   });
 
@@ -419,6 +549,7 @@ fn test_parse_next_4() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -452,6 +583,7 @@ fn test_parse_next_5() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -485,6 +617,7 @@ fn test_parse_next_6() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("TEST_VALUE".to_string()),
   });
 
@@ -518,6 +651,7 @@ fn test_parse_next_7() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: Some("TEST_VALUE".to_string()),
   });
 
@@ -551,6 +685,7 @@ fn test_parse_next_8() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -584,6 +719,7 @@ fn test_parse_next_9() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -614,6 +750,7 @@ fn test_parse_next_10() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -644,6 +781,7 @@ fn test_parse_next_11() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -674,6 +812,7 @@ fn test_parse_next_12() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -704,6 +843,7 @@ fn test_parse_next_13() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("TEST_VALUE".to_string()),
   });
 
@@ -734,6 +874,7 @@ fn test_parse_next_14() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -764,6 +905,7 @@ fn test_parse_next_15() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: Some("TEST_VALUE".to_string()),
   });
 
@@ -794,6 +936,7 @@ fn test_parse_next_16() {
       name_short: 'U',
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -827,6 +970,7 @@ fn test_parse_unknown_0() {
         name_long: "UNKNOWN".to_string(),
       },
       known: None,
+      suggestions: Vec::new(),
       value: None,
     },
   ];
@@ -859,6 +1003,7 @@ fn test_parse_unknown_1() {
         name_short: 'U',
       },
       known: None,
+      suggestions: Vec::new(),
       value: None,
     },
   ];
@@ -890,6 +1035,7 @@ fn test_parse_unknown_2() {
         name_long: "".to_string(),
       },
       known: None,
+      suggestions: Vec::new(),
       value: None,
     },
   ];
@@ -940,3 +1086,33 @@ fn test_parse_unknown_4() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_parse_unknown_5() {
+  // The program name is skipped even if it looks like an option
+  let test_args: Vec<String> = vec![
+    "-x".to_string(),
+    "-y".to_string(),
+  ];
+
+  let mut test_parse_iterator: ParseIterator =
+    ParseIterator::new(&test_args, &[]);
+
+  let expected: Vec<ParseOutput> = vec![
+    ParseOutput {
+      error: None,
+      found: ParseFound::Short {
+        arg_index: 1,
+        char_index: 0,
+        name_short: 'y',
+      },
+      known: None,
+      suggestions: Vec::new(),
+      value: None,
+    },
+  ];
+
+  let actual: Vec<ParseOutput> = test_parse_iterator.parse_unknown();
+
+  assert_eq!(actual, expected);
+}
@@ -2,15 +2,16 @@
 //! Module parse_option_config
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-27
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use super::duplicate_policy::DuplicatePolicy;
 use super::parse_input::ParseInput;
 use super::parse_iterator::ParseIterator;
 use super::parse_option_name::ParseOptionName;
@@ -25,20 +26,206 @@ mod test;
 //------------------------------------------------------------------------------
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ParseOptionConfig<'a> {
+  /// Whether a full parse warns that the option is deprecated
+  pub deprecated: bool,
+  /// What a full parse does when the option occurs more than once
+  pub duplicate_policy: DuplicatePolicy,
+  /// Whether the option is left out of -\-help unless all options are shown
+  pub hidden: bool,
   pub id: &'a str,
   pub name: ParseOptionName<'a>,
+  /// The option named in the deprecation warning to use instead, such as
+  /// "--output"
+  pub replacement: Option<&'a str>,
+  /// Whether the option must be present in the command-line arguments
+  pub required: bool,
   pub value_usage: ValueUsage,
 }
 
 impl<'a> ParseOptionConfig<'a> {
+  //----------------------------------------------------------------------------
+  /// Panics if the two options share an id, a long name, or a short name
+  ///
+  /// - Usable in a const context so the check fails the build
+  //----------------------------------------------------------------------------
+  pub const fn assert_distinct(
+    &self,
+    other: &ParseOptionConfig,
+  ) {
+    if str_eq(self.id, other.id) {
+      panic!("Duplicate option id");
+    }
+
+    if let (Some(name_long), Some(other_name_long)) =
+      (self.name.get_name_long(), other.name.get_name_long())
+      && str_eq(name_long, other_name_long)
+    {
+      panic!("Duplicate option long name");
+    }
+
+    if let (Some(name_short), Some(other_name_short)) =
+      (self.name.get_name_short(), other.name.get_name_short())
+      && name_short == other_name_short
+    {
+      panic!("Duplicate option short name");
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Panics if the option has an empty id or a name that cannot be matched
+  ///
+  /// - Rejects empty long names and names with "=" or a leading "-"
+  /// - Usable in a const context so the check fails the build
+  //----------------------------------------------------------------------------
+  pub const fn assert_valid(&self) {
+    if self.id.is_empty() {
+      panic!("Empty option id");
+    }
+
+    if let Some(name_long) = self.name.get_name_long() {
+      let bytes: &[u8] = name_long.as_bytes();
+
+      if bytes.is_empty() {
+        panic!("Empty option long name");
+      }
+
+      if bytes[0] == b'-' {
+        panic!("Option long name starts with a hyphen");
+      }
+
+      let mut index: usize = 0;
+
+      while index < bytes.len() {
+        if bytes[index] == b'=' {
+          panic!("Option long name contains an equals sign");
+        }
+
+        index += 1;
+      }
+    }
+
+    if let Some(name_short) = self.name.get_name_short()
+      && (name_short == '-' || name_short == '=')
+    {
+      panic!("Option short name is a hyphen or an equals sign");
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Panics if any option is invalid or if any two options are not distinct
+  ///
+  /// - Use with a const item to check an option table when building:
+  ///   `const _: () = ParseOptionConfig::assert_valid_slice(&CONFIGS);`
+  /// - See ParseInput::validate for a runtime check with a list of issues
+  //----------------------------------------------------------------------------
+  pub const fn assert_valid_slice(parse_option_configs: &[&ParseOptionConfig]) {
+    let mut index: usize = 0;
+
+    while index < parse_option_configs.len() {
+      let parse_option_config: &ParseOptionConfig = parse_option_configs[index];
+
+      parse_option_config.assert_valid();
+
+      let mut other_index: usize = index + 1;
+
+      while other_index < parse_option_configs.len() {
+        parse_option_config.assert_distinct(parse_option_configs[other_index]);
+
+        other_index += 1;
+      }
+
+      index += 1;
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be deprecated so that a full parse warns if present
+  //----------------------------------------------------------------------------
+  pub const fn deprecated(mut self) -> Self {
+    self.deprecated = true;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets what a full parse does when the option occurs more than once
+  //----------------------------------------------------------------------------
+  pub const fn duplicate_policy(
+    mut self,
+    duplicate_policy: DuplicatePolicy,
+  ) -> Self {
+    self.duplicate_policy = duplicate_policy;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be accepted but left out of -\-help
+  //----------------------------------------------------------------------------
+  pub const fn hidden(mut self) -> Self {
+    self.hidden = true;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the id which otherwise defaults to the long name
+  //----------------------------------------------------------------------------
+  pub const fn id(
+    mut self,
+    id: &'a str,
+  ) -> Self {
+    self.id = id;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// An option with a long name which is also used as the id
+  ///
+  /// - See new for the defaults
+  //----------------------------------------------------------------------------
+  pub const fn long(name_long: &'a str) -> Self {
+    Self::new(name_long, ParseOptionName::Long(name_long))
+  }
+
+  //----------------------------------------------------------------------------
+  /// An option with the id and name and with defaults for everything else
+  ///
+  /// - Accumulates duplicates, is not required, does not take a value, and is
+  ///   neither deprecated nor hidden
+  //----------------------------------------------------------------------------
+  pub const fn new(
+    id: &'a str,
+    name: ParseOptionName<'a>,
+  ) -> Self {
+    Self {
+      deprecated: false,
+      duplicate_policy: DuplicatePolicy::Accumulate,
+      hidden: false,
+      id,
+      name,
+      replacement: None,
+      required: false,
+      value_usage: ValueUsage::Verboten,
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to take an optional value
+  //----------------------------------------------------------------------------
+  pub const fn optional_value(self) -> Self {
+    self.value_usage(ValueUsage::Optional)
+  }
+
   pub fn parse(
     &self,
     args: &'a [String],
   ) -> Vec<ParseOutput> {
-    let parse_input: ParseInput<'_> = ParseInput {
-      args,
-      parse_option_configs: &[self],
-    };
+    let parse_option_configs: [&ParseOptionConfig; 1] = [self];
+
+    let parse_input: ParseInput<'_> =
+      ParseInput::from_args_without_program_name(args, &parse_option_configs);
 
     let parse_iterator: ParseIterator = parse_input.into_iter();
 
@@ -55,4 +242,102 @@ impl<'a> ParseOptionConfig<'a> {
 
     parse_output_vec.pop()
   }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be deprecated in favor of the replacement option
+  ///
+  /// - The replacement is named in the warning, such as "--output"
+  //----------------------------------------------------------------------------
+  pub const fn replaced_by(
+    mut self,
+    replacement: &'a str,
+  ) -> Self {
+    self.deprecated = true;
+
+    self.replacement = Some(replacement);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be required in the command-line arguments
+  //----------------------------------------------------------------------------
+  pub const fn required(mut self) -> Self {
+    self.required = true;
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to take a required value
+  //----------------------------------------------------------------------------
+  pub const fn required_value(self) -> Self {
+    self.value_usage(ValueUsage::Required)
+  }
+
+  //----------------------------------------------------------------------------
+  /// Adds a short name to the long name or replaces the short name
+  //----------------------------------------------------------------------------
+  pub const fn short(
+    mut self,
+    name_short: char,
+  ) -> Self {
+    self.name = match self.name {
+      ParseOptionName::Both {
+        name_long,
+        ..
+      }
+      | ParseOptionName::Long(name_long) => ParseOptionName::Both {
+        name_long,
+        name_short,
+      },
+      ParseOptionName::Short(_) => ParseOptionName::Short(name_short),
+    };
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets whether the option takes a value
+  //----------------------------------------------------------------------------
+  pub const fn value_usage(
+    mut self,
+    value_usage: ValueUsage,
+  ) -> Self {
+    self.value_usage = value_usage;
+
+    self
+  }
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// Compares two strings in a const context
+//------------------------------------------------------------------------------
+const fn str_eq(
+  a: &str,
+  b: &str,
+) -> bool {
+  let a_bytes: &[u8] = a.as_bytes();
+
+  let b_bytes: &[u8] = b.as_bytes();
+
+  if a_bytes.len() != b_bytes.len() {
+    return false;
+  }
+
+  let mut index: usize = 0;
+
+  while index < a_bytes.len() {
+    if a_bytes[index] != b_bytes[index] {
+      return false;
+    }
+
+    index += 1;
+  }
+
+  true
 }
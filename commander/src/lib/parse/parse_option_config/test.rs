@@ -2,10 +2,10 @@
 //! Unit tests for module parse_option_config
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-02
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -20,32 +20,137 @@ const TEST_ID_1: &str = "TEST_ID_1";
 const TEST_ID_2: &str = "TEST_ID_2";
 
 const PARSE_OPTION_CONFIG_OPTION: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_0,
   name: ParseOptionName::Both {
     name_long: "TEST",
     name_short: 'T',
   },
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Optional,
 };
 
 const PARSE_OPTION_CONFIG_REQUIRED: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_1,
   name: ParseOptionName::Both {
     name_long: "TEST",
     name_short: 'T',
   },
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Required,
 };
 
 const PARSE_OPTION_CONFIG_VERBOTEN: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
   id: TEST_ID_2,
   name: ParseOptionName::Both {
     name_long: "TEST",
     name_short: 'T',
   },
+  replacement: None,
+  required: false,
   value_usage: ValueUsage::Verboten,
 };
 
+//------------------------------------------------------------------------------
+// assert_valid_slice() unit tests
+//------------------------------------------------------------------------------
+
+const _: () = ParseOptionConfig::assert_valid_slice(&[
+  &PARSE_OPTION_CONFIG_OPTION,
+  &ParseOptionConfig {
+    id: TEST_ID_1,
+    name: ParseOptionName::Long("OTHER"),
+    ..PARSE_OPTION_CONFIG_REQUIRED
+  },
+]);
+
+#[test]
+#[should_panic(expected = "Duplicate option long name")]
+fn test_assert_valid_slice_0() {
+  ParseOptionConfig::assert_valid_slice(&[
+    &PARSE_OPTION_CONFIG_OPTION,
+    &PARSE_OPTION_CONFIG_REQUIRED,
+  ]);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate option id")]
+fn test_assert_valid_slice_1() {
+  ParseOptionConfig::assert_valid_slice(&[
+    &PARSE_OPTION_CONFIG_OPTION,
+    &PARSE_OPTION_CONFIG_OPTION,
+  ]);
+}
+
+#[test]
+#[should_panic(expected = "Empty option long name")]
+fn test_assert_valid_slice_2() {
+  ParseOptionConfig::assert_valid_slice(&[
+    &ParseOptionConfig {
+      name: ParseOptionName::Long(""),
+      ..PARSE_OPTION_CONFIG_OPTION
+    },
+  ]);
+}
+
+#[test]
+#[should_panic(expected = "Option long name contains an equals sign")]
+fn test_assert_valid_slice_3() {
+  ParseOptionConfig::assert_valid_slice(&[
+    &ParseOptionConfig {
+      name: ParseOptionName::Long("a=b"),
+      ..PARSE_OPTION_CONFIG_OPTION
+    },
+  ]);
+}
+
+//------------------------------------------------------------------------------
+// builder unit tests
+//------------------------------------------------------------------------------
+
+#[test]
+fn test_long_0() {
+  let actual: ParseOptionConfig = ParseOptionConfig::long("TEST")
+    .id(TEST_ID_1)
+    .short('T')
+    .required_value();
+
+  assert_eq!(actual, PARSE_OPTION_CONFIG_REQUIRED);
+}
+
+#[test]
+fn test_new_0() {
+  let expected: ParseOptionConfig = ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    hidden: false,
+    id: TEST_ID_0,
+    name: ParseOptionName::Short('T'),
+    replacement: None,
+    required: true,
+    value_usage: ValueUsage::Optional,
+  };
+
+  let actual: ParseOptionConfig =
+    ParseOptionConfig::new(TEST_ID_0, ParseOptionName::Short('S'))
+      .short('T')
+      .duplicate_policy(DuplicatePolicy::LastWins)
+      .optional_value()
+      .required();
+
+  assert_eq!(actual, expected);
+}
+
 //------------------------------------------------------------------------------
 // parse() unit tests
 //------------------------------------------------------------------------------
@@ -65,6 +170,7 @@ fn test_parse_0() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: None,
     },
     ParseOutput {
@@ -75,6 +181,7 @@ fn test_parse_0() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: Some("A".to_string()),
     },
     ParseOutput {
@@ -85,6 +192,7 @@ fn test_parse_0() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: Some("B".to_string()),
     },
   ];
@@ -122,6 +230,7 @@ fn test_parse_2() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: None,
     },
     ParseOutput {
@@ -132,6 +241,7 @@ fn test_parse_2() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: Some("A".to_string()),
     },
     ParseOutput {
@@ -142,6 +252,7 @@ fn test_parse_2() {
         name_short: 'T',
       },
       known: Some(TEST_ID_0.to_string()),
+      suggestions: Vec::new(),
       value: Some("B".to_string()),
     },
   ];
@@ -169,6 +280,7 @@ fn test_parse_last_0() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("B".to_string()),
   });
 
@@ -210,6 +322,7 @@ fn test_parse_last_option_0() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -231,6 +344,7 @@ fn test_parse_last_option_1() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -252,6 +366,7 @@ fn test_parse_last_option_2() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -273,6 +388,7 @@ fn test_parse_last_option_3() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -298,6 +414,7 @@ fn test_parse_last_option_4() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -318,6 +435,7 @@ fn test_parse_last_option_5() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -338,6 +456,7 @@ fn test_parse_last_option_6() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -358,6 +477,7 @@ fn test_parse_last_option_7() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -379,6 +499,7 @@ fn test_parse_last_option_8() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -400,6 +521,7 @@ fn test_parse_last_option_9() {
       name_short: 'T',
     },
     known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -423,6 +545,7 @@ fn test_parse_last_required_0() {
       name_short: 'T',
     },
     known: Some(TEST_ID_1.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -444,6 +567,7 @@ fn test_parse_last_required_1() {
       name_short: 'T',
     },
     known: Some(TEST_ID_1.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -465,6 +589,7 @@ fn test_parse_last_required_2() {
       name_short: 'T',
     },
     known: Some(TEST_ID_1.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -486,6 +611,7 @@ fn test_parse_last_required_3() {
       name_short: 'T',
     },
     known: Some(TEST_ID_1.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -532,6 +658,7 @@ fn test_parse_last_required_multiple_1() {
       name_short: 'T',
     },
     known: Some(TEST_ID_1.to_string()),
+    suggestions: Vec::new(),
     value: Some("1".to_string()),
   });
 
@@ -567,6 +694,7 @@ fn test_parse_last_verboten_0() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -590,6 +718,7 @@ fn test_parse_last_verboten_1() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -611,6 +740,7 @@ fn test_parse_last_verboten_2() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -631,6 +761,7 @@ fn test_parse_last_verboten_3() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -653,6 +784,7 @@ fn test_parse_last_verboten_4() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -673,6 +805,7 @@ fn test_parse_last_verboten_5() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -694,6 +827,7 @@ fn test_parse_last_verboten_6() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -714,6 +848,7 @@ fn test_parse_last_verboten_7() {
       name_long: "TEST".to_string(),
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -735,6 +870,7 @@ fn test_parse_last_verboten_8() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: Some("value".to_string()),
   });
 
@@ -756,6 +892,7 @@ fn test_parse_last_verboten_9() {
       name_short: 'T',
     },
     known: Some(TEST_ID_2.to_string()),
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -0,0 +1,122 @@
+//==============================================================================
+//! Module for ParseOptionError
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::parse_error::ParseError;
+use super::parse_found::ParseFound;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// An error parsing an option with the context of where it was found
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseOptionError {
+  pub error: ParseError,
+  /// A description of the expected form of the option
+  pub expected: String,
+  pub found: ParseFound,
+  /// The id of the option configuration that matched
+  pub id: Option<String>,
+  /// The offending value, if any
+  pub value: Option<String>,
+}
+
+impl ParseOptionError {
+  //----------------------------------------------------------------------------
+  /// Makes the expected form of the option from the error and where it was found
+  ///
+  /// - The expected form shows the value as "<VALUE>" until with_value_name
+  //----------------------------------------------------------------------------
+  pub fn new(
+    error: ParseError,
+    found: ParseFound,
+    id: Option<String>,
+    value: Option<String>,
+  ) -> Self {
+    let expected: String = make_expected(&error, &found, "VALUE");
+
+    Self {
+      error,
+      expected,
+      found,
+      id,
+      value,
+    }
+  }
+
+  pub fn get_arg_index(&self) -> usize {
+    self.found.get_arg_index()
+  }
+
+  pub fn get_name(&self) -> String {
+    self.found.get_name()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Remakes the expected form using the value name such as "FILE"
+  //----------------------------------------------------------------------------
+  pub fn with_value_name(
+    mut self,
+    value_name: &str,
+  ) -> Self {
+    self.expected = make_expected(&self.error, &self.found, value_name);
+
+    self
+  }
+}
+
+impl Display for ParseOptionError {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    write!(formatter, "{} for option at {}", self.error, self.found)
+  }
+}
+
+impl Error for ParseOptionError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    Some(&self.error)
+  }
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+fn make_expected(
+  error: &ParseError,
+  found: &ParseFound,
+  value_name: &str,
+) -> String {
+  let hyphenated_name: String = found.make_hyphenated_name();
+
+  match error {
+    ParseError::DuplicateOption {
+      ..
+    } => format!("{hyphenated_name} only once"),
+    ParseError::InvalidValue => {
+      format!("{hyphenated_name} with a valid value")
+    },
+    ParseError::RequiredValueMissing | ParseError::ValueMissingAfterEquals => {
+      format!("{hyphenated_name}=<{value_name}>")
+    },
+    ParseError::VerbotenValuePresent => {
+      format!("{hyphenated_name} without a value")
+    },
+  }
+}
@@ -0,0 +1,106 @@
+//==============================================================================
+//! Unit tests for module parse_option_error
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+#[test]
+fn test_new_0() {
+  let test_parse_option_error: ParseOptionError = ParseOptionError::new(
+    ParseError::RequiredValueMissing,
+    ParseFound::Long {
+      arg_index: 1,
+      name_long: "name".to_string(),
+    },
+    Some("TEST_ID".to_string()),
+    None,
+  );
+
+  assert_eq!(test_parse_option_error.expected, "--name=<VALUE>");
+
+  assert_eq!(test_parse_option_error.get_arg_index(), 1);
+
+  assert_eq!(test_parse_option_error.get_name(), "name");
+}
+
+#[test]
+fn test_new_1() {
+  let test_parse_option_error: ParseOptionError = ParseOptionError::new(
+    ParseError::VerbotenValuePresent,
+    ParseFound::Short {
+      arg_index: 2,
+      char_index: 0,
+      name_short: 'q',
+    },
+    None,
+    Some("value".to_string()),
+  );
+
+  assert_eq!(test_parse_option_error.expected, "-q without a value");
+}
+
+#[test]
+fn test_with_value_name_0() {
+  let test_parse_option_error: ParseOptionError = ParseOptionError::new(
+    ParseError::ValueMissingAfterEquals,
+    ParseFound::Short {
+      arg_index: 1,
+      char_index: 0,
+      name_short: 'o',
+    },
+    Some("TEST_ID".to_string()),
+    None,
+  )
+  .with_value_name("FILE");
+
+  assert_eq!(test_parse_option_error.expected, "-o=<FILE>");
+}
+
+#[test]
+fn test_source_0() {
+  let test_parse_option_error: ParseOptionError = ParseOptionError::new(
+    ParseError::InvalidValue,
+    ParseFound::Long {
+      arg_index: 1,
+      name_long: "count".to_string(),
+    },
+    Some("TEST_ID".to_string()),
+    Some("x".to_string()),
+  );
+
+  let source: Option<&(dyn Error + 'static)> = test_parse_option_error.source();
+
+  assert_eq!(
+    source.map(ToString::to_string).as_deref(),
+    Some("Invalid value")
+  );
+}
+
+#[test]
+fn test_to_string_0() {
+  let test_parse_option_error: ParseOptionError = ParseOptionError::new(
+    ParseError::DuplicateOption {
+      first_arg_index: 1,
+    },
+    ParseFound::Long {
+      arg_index: 3,
+      name_long: "name".to_string(),
+    },
+    Some("TEST_ID".to_string()),
+    None,
+  );
+
+  let expected: &str =
+    "Duplicate of argument index 1 for option at argument index 3: \"name\"";
+
+  assert_eq!(test_parse_option_error.to_string(), expected);
+}
@@ -2,10 +2,10 @@
 //! Module parse_option_name
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-19
-//! - Updated: 2024-08-01
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -25,7 +25,7 @@ pub enum ParseOptionName<'a> {
 }
 
 impl ParseOptionName<'_> {
-  pub fn get_name_long(&self) -> Option<&'_ str> {
+  pub const fn get_name_long(&self) -> Option<&'_ str> {
     match self {
       ParseOptionName::Both {
         name_long,
@@ -36,7 +36,7 @@ impl ParseOptionName<'_> {
     }
   }
 
-  pub fn get_name_short(&self) -> Option<char> {
+  pub const fn get_name_short(&self) -> Option<char> {
     match self {
       ParseOptionName::Both {
         name_short,
@@ -46,4 +46,18 @@ impl ParseOptionName<'_> {
       ParseOptionName::Short(name_short) => Some(*name_short),
     }
   }
+
+  //----------------------------------------------------------------------------
+  /// The long name with two hyphens if available, otherwise the short name
+  //----------------------------------------------------------------------------
+  pub fn make_hyphenated_name(&self) -> String {
+    match self {
+      ParseOptionName::Both {
+        name_long,
+        ..
+      } => format!("--{name_long}"),
+      ParseOptionName::Long(name_long) => format!("--{name_long}"),
+      ParseOptionName::Short(name_short) => format!("-{name_short}"),
+    }
+  }
 }
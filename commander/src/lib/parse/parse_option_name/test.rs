@@ -2,10 +2,10 @@
 //! Unit tests for module parse_option_name
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-20
-//! - Updated: 2024-06-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -81,3 +81,21 @@ fn test_get_name_short_2() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_make_hyphenated_name_0() {
+  let expected: String = format!("--{TEST_NAME_LONG}");
+
+  let actual: String = TEST_PARSE_OPTION_NAME_BOTH.make_hyphenated_name();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_hyphenated_name_1() {
+  let expected: String = format!("-{TEST_NAME_SHORT}");
+
+  let actual: String = TEST_PARSE_OPTION_NAME_SHORT.make_hyphenated_name();
+
+  assert_eq!(actual, expected);
+}
@@ -5,7 +5,7 @@
 //! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-27
-//! - Updated: 2026-04-18
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,6 +14,7 @@
 use crate::parse::parse_found::ParseFound;
 
 use super::parse_error::ParseError;
+use super::parse_option_error::ParseOptionError;
 
 #[cfg(test)]
 mod test;
@@ -27,6 +28,8 @@ pub struct ParseOutput {
   pub found: ParseFound,
   /// The id of the option configuration that matched
   pub known: Option<String>,
+  /// Hyphenated names of known options similar to an unknown option
+  pub suggestions: Vec<String>,
   // TODO: Does this need to be OsString?
   pub value: Option<String>,
 }
@@ -60,4 +63,18 @@ impl ParseOutput {
       _ => Err(ParseError::InvalidValue),
     }
   }
+
+  //----------------------------------------------------------------------------
+  /// The error with its context if the error is Some
+  //----------------------------------------------------------------------------
+  pub fn to_error(&self) -> Option<ParseOptionError> {
+    let error: ParseError = self.error?;
+
+    Some(ParseOptionError::new(
+      error,
+      self.found.clone(),
+      self.known.clone(),
+      self.value.clone(),
+    ))
+  }
 }
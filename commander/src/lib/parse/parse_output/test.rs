@@ -2,10 +2,10 @@
 //! Unit tests for module parse_output
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-05-29
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -22,6 +22,7 @@ fn test_to_bool_result_0() {
       name_long: String::new(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   };
 
@@ -41,6 +42,7 @@ fn test_to_bool_result_1() {
       name_long: String::new(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: Some("0".to_string()),
   };
 
@@ -60,6 +62,7 @@ fn test_to_bool_result_2() {
       name_long: String::new(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: Some("1".to_string()),
   };
 
@@ -69,3 +72,25 @@ fn test_to_bool_result_2() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_to_error_0() {
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::VerbotenValuePresent),
+    found: ParseFound::Long {
+      arg_index: 0,
+      name_long: "TEST".to_string(),
+    },
+    known: Some("TEST_ID".to_string()),
+    suggestions: Vec::new(),
+    value: Some("value".to_string()),
+  };
+
+  let actual: ParseOptionError = test_parse_output.to_error().unwrap();
+
+  assert_eq!(actual.expected, "--TEST without a value");
+
+  assert_eq!(actual.id.as_deref(), Some("TEST_ID"));
+
+  assert_eq!(actual.value.as_deref(), Some("value"));
+}
@@ -0,0 +1,38 @@
+//==============================================================================
+//! Module for ParseRelation
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+//------------------------------------------------------------------------------
+/// A relationship between two options identified by their configuration ids
+///
+/// - Evaluated after a full parse of the command-line arguments
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseRelation<'a> {
+  /// The two options cannot both be present
+  Conflicts {
+    conflicting_id: &'a str,
+    id: &'a str,
+  },
+  /// If the option is present, the implied option is treated as present
+  Implies {
+    id: &'a str,
+    implied_id: &'a str,
+    /// The value given to the implied option
+    implied_value: Option<&'a str>,
+  },
+  /// If the option is present, the required option must also be present
+  Requires {
+    id: &'a str,
+    required_id: &'a str,
+  },
+}
@@ -0,0 +1,216 @@
+//==============================================================================
+//! Module for ParseResults
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::str::FromStr;
+
+use super::parse_error::ParseError;
+use super::parse_option_error::ParseOptionError;
+use super::parse_output::ParseOutput;
+use super::parse_rule_error::ParseRuleError;
+use super::parse_warning::ParseWarning;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// The results of a full parse of the command-line arguments
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseResults {
+  /// The known and unknown options in the order found
+  pub parse_outputs: Vec<ParseOutput>,
+  /// The errors found when checking the options after the parse
+  pub rule_errors: Vec<ParseRuleError>,
+  /// The problems found which are not errors, such as deprecated options
+  pub warnings: Vec<ParseWarning>,
+}
+
+impl ParseResults {
+  //----------------------------------------------------------------------------
+  /// Converts the values of the options with the option configuration id
+  ///
+  /// - Skips options with errors and options without values
+  /// - Marks an option with an invalid value error if the conversion fails
+  //----------------------------------------------------------------------------
+  pub fn convert_all<T: FromStr>(
+    &mut self,
+    id: &str,
+  ) -> Vec<T> {
+    let mut converted: Vec<T> = Vec::new();
+
+    for parse_output in self.parse_outputs.iter_mut() {
+      if parse_output.known.as_deref() != Some(id)
+        || parse_output.error.is_some()
+      {
+        continue;
+      }
+
+      let Some(value) = &parse_output.value else {
+        continue;
+      };
+
+      match value.parse::<T>() {
+        Ok(converted_value) => converted.push(converted_value),
+        Err(_) => parse_output.error = Some(ParseError::InvalidValue),
+      }
+    }
+
+    converted
+  }
+
+  //----------------------------------------------------------------------------
+  /// Converts the value of the last option with the option configuration id
+  ///
+  /// - Returns None if the option is missing, has an error, or has no value
+  /// - Marks the option with an invalid value error if the conversion fails
+  //----------------------------------------------------------------------------
+  pub fn convert_last<T: FromStr>(
+    &mut self,
+    id: &str,
+  ) -> Option<T> {
+    let parse_output: &mut ParseOutput = self.get_last_mut(id)?;
+
+    if parse_output.error.is_some() {
+      return None;
+    }
+
+    let result: Result<T, T::Err> = parse_output.value.as_ref()?.parse::<T>();
+
+    if result.is_err() {
+      parse_output.error = Some(ParseError::InvalidValue);
+    }
+
+    result.ok()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Converts the last option with the option configuration id to a bool
+  ///
+  /// - An option without a value is true as in ParseOutput::to_bool_result
+  /// - Returns None if the option is missing or has an error
+  /// - Marks the option with an invalid value error if the conversion fails
+  //----------------------------------------------------------------------------
+  pub fn convert_last_bool(
+    &mut self,
+    id: &str,
+  ) -> Option<bool> {
+    let parse_output: &mut ParseOutput = self.get_last_mut(id)?;
+
+    match parse_output.to_bool_result() {
+      Ok(converted_value) => Some(converted_value),
+      Err(parse_error) => {
+        parse_output.error.get_or_insert(parse_error);
+
+        None
+      },
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the options with parsing errors
+  //----------------------------------------------------------------------------
+  pub fn get_errors(&self) -> Vec<ParseOutput> {
+    self
+      .parse_outputs
+      .iter()
+      .filter(|parse_output: &&ParseOutput| parse_output.error.is_some())
+      .cloned()
+      .collect()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the errors of the options with parsing errors with their context
+  //----------------------------------------------------------------------------
+  pub fn get_option_errors(&self) -> Vec<ParseOptionError> {
+    self
+      .parse_outputs
+      .iter()
+      .filter_map(ParseOutput::to_error)
+      .collect()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the options with the option configuration id
+  //----------------------------------------------------------------------------
+  pub fn get_known(
+    &self,
+    id: &str,
+  ) -> Vec<ParseOutput> {
+    self
+      .parse_outputs
+      .iter()
+      .filter(|parse_output: &&ParseOutput| {
+        parse_output.known.as_deref() == Some(id)
+      })
+      .cloned()
+      .collect()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the last option with the option configuration id
+  //----------------------------------------------------------------------------
+  pub fn get_last(
+    &self,
+    id: &str,
+  ) -> Option<ParseOutput> {
+    self.get_known(id).pop()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the last option with the option configuration id for updating
+  //----------------------------------------------------------------------------
+  pub fn get_last_mut(
+    &mut self,
+    id: &str,
+  ) -> Option<&mut ParseOutput> {
+    self.parse_outputs.iter_mut().rev().find(
+      |parse_output: &&mut ParseOutput| {
+        parse_output.known.as_deref() == Some(id)
+      },
+    )
+  }
+
+  //----------------------------------------------------------------------------
+  /// Returns the unknown options
+  //----------------------------------------------------------------------------
+  pub fn get_unknown(&self) -> Vec<ParseOutput> {
+    self
+      .parse_outputs
+      .iter()
+      .filter(|parse_output: &&ParseOutput| parse_output.known.is_none())
+      .cloned()
+      .collect()
+  }
+
+  //----------------------------------------------------------------------------
+  /// Whether there are parsing errors, unknown options, or rule errors
+  //----------------------------------------------------------------------------
+  pub fn has_errors(&self) -> bool {
+    !self.rule_errors.is_empty()
+      || self.parse_outputs.iter().any(|parse_output: &ParseOutput| {
+        parse_output.error.is_some() || parse_output.known.is_none()
+      })
+  }
+
+  //----------------------------------------------------------------------------
+  /// Whether an option with the option configuration id is present
+  //----------------------------------------------------------------------------
+  pub fn is_present(
+    &self,
+    id: &str,
+  ) -> bool {
+    self.parse_outputs.iter().any(|parse_output: &ParseOutput| {
+      parse_output.known.as_deref() == Some(id)
+    })
+  }
+}
@@ -0,0 +1,213 @@
+//==============================================================================
+//! Unit tests for module parse_results
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::sync::LazyLock;
+
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
+
+use super::*;
+
+const TEST_ID_0: &str = "TEST_ID_0";
+
+static TEST_PARSE_OUTPUT_0: LazyLock<ParseOutput> =
+  LazyLock::new(|| ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 0,
+      name_long: "TEST".to_string(),
+    },
+    known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
+    value: Some("A".to_string()),
+  });
+
+static TEST_PARSE_OUTPUT_1: LazyLock<ParseOutput> =
+  LazyLock::new(|| ParseOutput {
+    error: Some(ParseError::ValueMissingAfterEquals),
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "TEST".to_string(),
+    },
+    known: Some(TEST_ID_0.to_string()),
+    suggestions: Vec::new(),
+    value: None,
+  });
+
+static TEST_PARSE_OUTPUT_2: LazyLock<ParseOutput> =
+  LazyLock::new(|| ParseOutput {
+    error: None,
+    found: ParseFound::Short {
+      arg_index: 2,
+      char_index: 0,
+      name_short: 'U',
+    },
+    known: None,
+    suggestions: Vec::new(),
+    value: None,
+  });
+
+static TEST_PARSE_RESULTS_0: LazyLock<ParseResults> =
+  LazyLock::new(|| ParseResults {
+    parse_outputs: vec![
+      TEST_PARSE_OUTPUT_0.clone(),
+      TEST_PARSE_OUTPUT_1.clone(),
+      TEST_PARSE_OUTPUT_2.clone(),
+    ],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  });
+
+#[test]
+fn test_convert_all_0() {
+  let mut test_parse_results: ParseResults = TEST_PARSE_RESULTS_0.clone();
+
+  let actual: Vec<char> = test_parse_results.convert_all(TEST_ID_0);
+
+  assert_eq!(actual, vec!['A']);
+}
+
+#[test]
+fn test_convert_last_0() {
+  let mut test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![TEST_PARSE_OUTPUT_0.clone()],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  let actual: Option<u8> = test_parse_results.convert_last(TEST_ID_0);
+
+  assert_eq!(actual, None);
+
+  assert_eq!(
+    test_parse_results.parse_outputs[0].error,
+    Some(ParseError::InvalidValue)
+  );
+}
+
+#[test]
+fn test_convert_last_1() {
+  let mut test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![TEST_PARSE_OUTPUT_0.clone()],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  let actual: Option<String> = test_parse_results.convert_last(TEST_ID_0);
+
+  assert_eq!(actual, Some("A".to_string()));
+
+  assert!(!test_parse_results.has_errors());
+}
+
+#[test]
+fn test_convert_last_bool_0() {
+  let mut test_parse_output: ParseOutput = TEST_PARSE_OUTPUT_0.clone();
+
+  test_parse_output.value = Some("off".to_string());
+
+  let mut test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![test_parse_output],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  assert_eq!(test_parse_results.convert_last_bool(TEST_ID_0), Some(false));
+
+  assert_eq!(test_parse_results.convert_last_bool("TEST_ID_1"), None);
+}
+
+#[test]
+fn test_convert_last_bool_1() {
+  let mut test_parse_results: ParseResults = TEST_PARSE_RESULTS_0.clone();
+
+  assert_eq!(test_parse_results.convert_last_bool(TEST_ID_0), None);
+}
+
+#[test]
+fn test_get_errors_0() {
+  let expected: Vec<ParseOutput> = vec![TEST_PARSE_OUTPUT_1.clone()];
+
+  let actual: Vec<ParseOutput> = TEST_PARSE_RESULTS_0.get_errors();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_get_known_0() {
+  let expected: Vec<ParseOutput> = vec![
+    TEST_PARSE_OUTPUT_0.clone(),
+    TEST_PARSE_OUTPUT_1.clone(),
+  ];
+
+  let actual: Vec<ParseOutput> = TEST_PARSE_RESULTS_0.get_known(TEST_ID_0);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_get_last_0() {
+  let expected: Option<ParseOutput> = Some(TEST_PARSE_OUTPUT_1.clone());
+
+  let actual: Option<ParseOutput> = TEST_PARSE_RESULTS_0.get_last(TEST_ID_0);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_get_unknown_0() {
+  let expected: Vec<ParseOutput> = vec![TEST_PARSE_OUTPUT_2.clone()];
+
+  let actual: Vec<ParseOutput> = TEST_PARSE_RESULTS_0.get_unknown();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_has_errors_0() {
+  assert!(TEST_PARSE_RESULTS_0.has_errors());
+}
+
+#[test]
+fn test_has_errors_1() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![TEST_PARSE_OUTPUT_0.clone()],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  assert!(!test_parse_results.has_errors());
+}
+
+#[test]
+fn test_has_errors_2() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: Vec::new(),
+    rule_errors: vec![
+      ParseRuleError::MissingOptions {
+        ids: vec![TEST_ID_0.to_string()],
+        names: vec!["--TEST".to_string()],
+      },
+    ],
+    warnings: Vec::new(),
+  };
+
+  assert!(test_parse_results.has_errors());
+}
+
+#[test]
+fn test_is_present_0() {
+  assert!(TEST_PARSE_RESULTS_0.is_present(TEST_ID_0));
+
+  assert!(!TEST_PARSE_RESULTS_0.is_present("TEST_ID_1"));
+}
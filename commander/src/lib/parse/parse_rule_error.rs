@@ -0,0 +1,117 @@
+//==============================================================================
+//! Module for ParseRuleError
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::parse_found::ParseFound;
+
+//------------------------------------------------------------------------------
+/// Errors found when checking the options after a full parse
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseRuleError {
+  /// Two options are present that conflict with each other
+  Conflict {
+    conflicting_found: ParseFound,
+    conflicting_id: String,
+    found: ParseFound,
+    id: String,
+  },
+  /// None of the options in a group that requires one are present
+  GroupMissing {
+    group_id: String,
+    /// The hyphenated names of the options in the group
+    names: Vec<String>,
+  },
+  /// More of the options in a group are present than the group allows
+  GroupMultiple {
+    /// Where the options in the group were found
+    founds: Vec<ParseFound>,
+    group_id: String,
+    /// The hyphenated names of the options in the group
+    names: Vec<String>,
+  },
+  /// Required options that are not present in the command-line arguments
+  MissingOptions {
+    /// The ids of the missing options
+    ids: Vec<String>,
+    /// The hyphenated names of the missing options, in the same order
+    names: Vec<String>,
+  },
+  /// An option is present without an option that it requires
+  RequirementMissing {
+    found: ParseFound,
+    id: String,
+    required_id: String,
+    /// The hyphenated name of the missing required option
+    required_name: String,
+  },
+}
+
+impl Display for ParseRuleError {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    match self {
+      ParseRuleError::Conflict {
+        conflicting_found,
+        found,
+        ..
+      } => write!(
+        formatter,
+        "Option at {found} conflicts with option at {conflicting_found}"
+      ),
+      ParseRuleError::GroupMissing {
+        group_id,
+        names,
+      } => write!(
+        formatter,
+        "Missing an option from group \"{}\": ({})",
+        group_id,
+        names.join(" | "),
+      ),
+      ParseRuleError::GroupMultiple {
+        founds,
+        group_id,
+        names,
+      } => {
+        let locations: Vec<String> =
+          founds.iter().map(ParseFound::to_string).collect();
+
+        write!(
+          formatter,
+          "Only one option from group \"{}\" allowed: ({}) but found at {}",
+          group_id,
+          names.join(" | "),
+          locations.join(" and at "),
+        )
+      },
+      ParseRuleError::MissingOptions {
+        names,
+        ..
+      } => write!(formatter, "Missing required options: {}", names.join(", ")),
+      ParseRuleError::RequirementMissing {
+        found,
+        required_name,
+        ..
+      } => write!(
+        formatter,
+        "Option at {found} requires missing option {required_name}"
+      ),
+    }
+  }
+}
+
+impl Error for ParseRuleError {}
@@ -0,0 +1,171 @@
+//==============================================================================
+//! Module for parse_suggestion
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::parse_found::ParseFound;
+use super::parse_option_config::ParseOptionConfig;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Edit distance allowing insertions, deletions, substitutions, and
+/// transpositions of adjacent characters (optimal string alignment)
+//------------------------------------------------------------------------------
+pub fn edit_distance(
+  a: &str,
+  b: &str,
+) -> usize {
+  let a_chars: Vec<char> = a.chars().collect();
+
+  let b_chars: Vec<char> = b.chars().collect();
+
+  let a_len: usize = a_chars.len();
+
+  let b_len: usize = b_chars.len();
+
+  let mut distances: Vec<Vec<usize>> = vec![vec![0; b_len + 1]; a_len + 1];
+
+  for (i, row) in distances.iter_mut().enumerate() {
+    row[0] = i;
+  }
+
+  for (j, distance) in distances[0].iter_mut().enumerate() {
+    *distance = j;
+  }
+
+  for i in 1..=a_len {
+    for j in 1..=b_len {
+      let cost: usize = if a_chars[i - 1] == b_chars[j - 1] {
+        0
+      } else {
+        1
+      };
+
+      let mut distance: usize = (distances[i - 1][j] + 1)
+        .min(distances[i][j - 1] + 1)
+        .min(distances[i - 1][j - 1] + cost);
+
+      if i > 1
+        && j > 1
+        && a_chars[i - 1] == b_chars[j - 2]
+        && a_chars[i - 2] == b_chars[j - 1]
+      {
+        distance = distance.min(distances[i - 2][j - 2] + 1);
+      }
+
+      distances[i][j] = distance;
+    }
+  }
+
+  distances[a_len][b_len]
+}
+
+//------------------------------------------------------------------------------
+/// Suggests the known option names closest to an unknown option
+///
+/// - Long names are compared by edit distance and by prefix
+/// - Short names are compared ignoring case
+/// - Returns hyphenated names such as "-\-name" or "-n", closest first
+//------------------------------------------------------------------------------
+pub fn make_suggestions(
+  parse_found: &ParseFound,
+  parse_option_configs: &[&ParseOptionConfig],
+) -> Vec<String> {
+  match parse_found {
+    ParseFound::Implied {
+      ..
+    } => Vec::new(),
+    ParseFound::Long {
+      name_long,
+      ..
+    } => {
+      let candidates: Vec<&str> = parse_option_configs
+        .iter()
+        .filter_map(|parse_option_config: &&ParseOptionConfig| {
+          parse_option_config.name.get_name_long()
+        })
+        .collect();
+
+      suggest(name_long, &candidates)
+        .into_iter()
+        .map(|candidate: &str| format!("--{candidate}"))
+        .collect()
+    },
+    ParseFound::Short {
+      name_short,
+      ..
+    } => {
+      let mut suggestions: Vec<String> = Vec::new();
+
+      for parse_option_config in parse_option_configs {
+        let Some(candidate) = parse_option_config.name.get_name_short() else {
+          continue;
+        };
+
+        if candidate != *name_short
+          && candidate.to_lowercase().eq(name_short.to_lowercase())
+        {
+          let suggestion: String = format!("-{candidate}");
+
+          if !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+          }
+        }
+      }
+
+      suggestions
+    },
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Returns the candidates close to the unknown name, closest first
+///
+/// - A candidate is close if it is within the edit distance threshold
+/// - A candidate is also close if it starts with an unknown name of two or
+///   more characters
+/// - The threshold is a third of the length of the unknown name, at least one
+/// - Can be used for any kind of name such as an option or a sub-command
+//------------------------------------------------------------------------------
+pub fn suggest<'a>(
+  unknown: &str,
+  candidates: &[&'a str],
+) -> Vec<&'a str> {
+  let unknown_len: usize = unknown.chars().count();
+
+  let threshold: usize = (unknown_len / 3).max(1);
+
+  let mut scored: Vec<(usize, &'a str)> = Vec::new();
+
+  for candidate in candidates {
+    if candidate.is_empty() || *candidate == unknown {
+      continue;
+    }
+
+    let distance: usize = edit_distance(unknown, candidate);
+
+    let is_prefix: bool = unknown_len >= 2 && candidate.starts_with(unknown);
+
+    if (distance <= threshold || is_prefix)
+      && !scored
+        .iter()
+        .any(|(_, scored_candidate)| scored_candidate == candidate)
+    {
+      scored.push((distance, candidate));
+    }
+  }
+
+  scored.sort();
+
+  scored.into_iter().map(|(_, candidate)| candidate).collect()
+}
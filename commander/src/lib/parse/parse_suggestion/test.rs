@@ -0,0 +1,173 @@
+//==============================================================================
+//! Unit tests for module parse_suggestion
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::parse_option_name::ParseOptionName;
+use crate::parse::value_usage::ValueUsage;
+
+use super::*;
+
+const TEST_PARSE_OPTION_CONFIG_0: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_0",
+  name: ParseOptionName::Both {
+    name_long: "name",
+    name_short: 'n',
+  },
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Required,
+};
+
+const TEST_PARSE_OPTION_CONFIG_1: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_1",
+  name: ParseOptionName::Long("interactive"),
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Optional,
+};
+
+const TEST_PARSE_OPTION_CONFIG_2: ParseOptionConfig = ParseOptionConfig {
+  deprecated: false,
+  duplicate_policy: DuplicatePolicy::Accumulate,
+  hidden: false,
+  id: "TEST_ID_2",
+  name: ParseOptionName::Short('Q'),
+  replacement: None,
+  required: false,
+  value_usage: ValueUsage::Verboten,
+};
+
+const TEST_PARSE_OPTION_CONFIGS: &[&ParseOptionConfig] = &[
+  &TEST_PARSE_OPTION_CONFIG_0,
+  &TEST_PARSE_OPTION_CONFIG_1,
+  &TEST_PARSE_OPTION_CONFIG_2,
+];
+
+#[test]
+fn test_edit_distance_0() {
+  assert_eq!(edit_distance("name", "name"), 0);
+}
+
+#[test]
+fn test_edit_distance_1() {
+  assert_eq!(edit_distance("nmae", "name"), 1);
+}
+
+#[test]
+fn test_edit_distance_2() {
+  assert_eq!(edit_distance("", "name"), 4);
+}
+
+#[test]
+fn test_edit_distance_3() {
+  assert_eq!(edit_distance("kitten", "sitting"), 3);
+}
+
+#[test]
+fn test_make_suggestions_0() {
+  let test_parse_found: ParseFound = ParseFound::Long {
+    arg_index: 1,
+    name_long: "nmae".to_string(),
+  };
+
+  let expected: Vec<String> = vec!["--name".to_string()];
+
+  let actual: Vec<String> =
+    make_suggestions(&test_parse_found, TEST_PARSE_OPTION_CONFIGS);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_suggestions_1() {
+  let test_parse_found: ParseFound = ParseFound::Long {
+    arg_index: 1,
+    name_long: "inter".to_string(),
+  };
+
+  let expected: Vec<String> = vec!["--interactive".to_string()];
+
+  let actual: Vec<String> =
+    make_suggestions(&test_parse_found, TEST_PARSE_OPTION_CONFIGS);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_suggestions_2() {
+  let test_parse_found: ParseFound = ParseFound::Long {
+    arg_index: 1,
+    name_long: "unknown".to_string(),
+  };
+
+  let expected: Vec<String> = Vec::new();
+
+  let actual: Vec<String> =
+    make_suggestions(&test_parse_found, TEST_PARSE_OPTION_CONFIGS);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_suggestions_3() {
+  let test_parse_found: ParseFound = ParseFound::Short {
+    arg_index: 1,
+    char_index: 0,
+    name_short: 'q',
+  };
+
+  let expected: Vec<String> = vec!["-Q".to_string()];
+
+  let actual: Vec<String> =
+    make_suggestions(&test_parse_found, TEST_PARSE_OPTION_CONFIGS);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_suggestions_4() {
+  let test_parse_found: ParseFound = ParseFound::Short {
+    arg_index: 1,
+    char_index: 0,
+    name_short: 'u',
+  };
+
+  let expected: Vec<String> = Vec::new();
+
+  let actual: Vec<String> =
+    make_suggestions(&test_parse_found, TEST_PARSE_OPTION_CONFIGS);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_suggest_0() {
+  let expected: Vec<&str> = vec![
+    "verbose", "version",
+  ];
+
+  let actual: Vec<&str> = suggest(
+    "ver",
+    &[
+      "version", "verbose", "quiet",
+    ],
+  );
+
+  assert_eq!(actual, expected);
+}
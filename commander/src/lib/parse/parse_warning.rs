@@ -0,0 +1,53 @@
+//==============================================================================
+//! Module for ParseWarning
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::fmt::{self, Display, Formatter};
+
+use super::parse_found::ParseFound;
+
+//------------------------------------------------------------------------------
+/// Problems found by a full parse which are not errors
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseWarning {
+  /// A deprecated option is present
+  Deprecated {
+    found: ParseFound,
+    id: String,
+    /// The option to use instead, if any
+    replacement: Option<String>,
+  },
+}
+
+impl Display for ParseWarning {
+  fn fmt(
+    &self,
+    formatter: &mut Formatter<'_>,
+  ) -> fmt::Result {
+    match self {
+      ParseWarning::Deprecated {
+        found,
+        replacement,
+        ..
+      } => {
+        write!(formatter, "Deprecated option at {found}")?;
+
+        if let Some(replacement) = replacement {
+          write!(formatter, ", use {replacement} instead")?;
+        }
+
+        Ok(())
+      },
+    }
+  }
+}
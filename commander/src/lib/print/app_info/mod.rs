@@ -5,32 +5,245 @@
 //! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-07
-//! - Updated: 2026-04-16
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use std::fmt;
+use std::io;
+
+use super::build_info::BuildInfo;
+use super::wrap;
+
 #[cfg(test)]
 mod test;
 
 //------------------------------------------------------------------------------
-/// Application data shown for the -\-help option
+/// Application data shown for the -\-help and -\-version options
 //------------------------------------------------------------------------------
 #[derive(Debug)]
 pub struct AppInfo<'a> {
   pub about: Option<&'a str>,
+  /// The authors shown for -\-version -\-verbose
+  pub authors: &'a [&'a str],
+  /// Where to report bugs, shown for -\-version -\-verbose
+  pub bug_report: Option<&'a str>,
   pub contact: Option<&'a str>,
   pub copyright: Option<&'a str>,
+  /// The home page URL shown for -\-version -\-verbose
+  pub homepage: Option<&'a str>,
+  /// The license such as "MIT", shown for -\-version -\-verbose
+  pub license: Option<&'a str>,
+  /// The full description shown for -\-help instead of the about text
+  pub long_about: Option<&'a str>,
   pub name: Option<&'a str>,
+  /// The version shown after the name for -\-help and -\-version
+  pub version: Option<&'a str>,
 }
 
 impl AppInfo<'_> {
+  //----------------------------------------------------------------------------
+  /// The application information with the long about text if available
+  ///
+  /// - Reads COLUMNS or queries the terminal, see wrap::get_terminal_width
+  /// - Use make_long_print_string_with_width for output that must not vary
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string(&self) -> String {
+    self.make_long_print_string_with_width(wrap::get_terminal_width())
+  }
+
+  //----------------------------------------------------------------------------
+  /// The application information with the long about text wrapped to a fixed
+  /// width
+  ///
+  /// - The paragraphs of the long about text are separated by a blank line
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string_with_width(
+    &self,
+    width: usize,
+  ) -> String {
+    let Some(long_about) = self.long_about else {
+      return self.make_print_string();
+    };
+
+    let paragraphs: Vec<String> = long_about
+      .split("\n\n")
+      .map(|paragraph: &str| {
+        wrap::wrap_text(paragraph, width.max(1)).join("\n")
+      })
+      .collect();
+
+    self.make_print_string_with_about(Some(&paragraphs.join("\n\n")))
+  }
+
   pub fn make_print_string(&self) -> String {
+    self.make_print_string_with_about(self.about)
+  }
+
+  //----------------------------------------------------------------------------
+  /// The version line followed by the application metadata and build
+  /// information, for -\-version -\-verbose
+  ///
+  /// - Shows one labeled line for each value present
+  //----------------------------------------------------------------------------
+  pub fn make_verbose_version_string(
+    &self,
+    build_info: &BuildInfo,
+  ) -> String {
+    let mut version_string: String = self.make_version_string();
+
+    if !self.authors.is_empty() {
+      version_string
+        .push_str(&format!("authors: {}\n", self.authors.join(", ")));
+    }
+
+    for (label, value_option) in [
+      ("homepage", self.homepage),
+      ("license", self.license),
+      ("bug reports", self.bug_report),
+    ] {
+      if let Some(value) = value_option {
+        version_string.push_str(&format!("{label}: {value}\n"));
+      }
+    }
+
+    version_string.push_str(&build_info.make_print_string());
+
+    version_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// The name followed by the version, for -\-version
+  //----------------------------------------------------------------------------
+  pub fn make_version_string(&self) -> String {
+    let mut version_string: String = self.make_name_line();
+
+    version_string.push('\n');
+
+    version_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the application information
+  //----------------------------------------------------------------------------
+  pub fn print(&self) {
+    print!("{}", self.make_print_string());
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the application information to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_to(
+    &self,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_print_string().as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the version with the application metadata and build information
+  //----------------------------------------------------------------------------
+  pub fn print_verbose_version(
+    &self,
+    build_info: &BuildInfo,
+  ) {
+    self
+      .print_verbose_version_to(build_info, &mut io::stdout())
+      .expect("failed printing to stdout");
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the version with the application metadata and build information to
+  /// an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_verbose_version_to(
+    &self,
+    build_info: &BuildInfo,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_verbose_version_string(build_info).as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the name followed by the version
+  //----------------------------------------------------------------------------
+  pub fn print_version(&self) {
+    self
+      .print_version_to(&mut io::stdout())
+      .expect("failed printing to stdout");
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the name followed by the version to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_version_to(
+    &self,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_version_string().as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the application information to a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_to(
+    &self,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_print_string())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the version with the application metadata and build information to
+  /// a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_verbose_version_to(
+    &self,
+    build_info: &BuildInfo,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_verbose_version_string(build_info))
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the name followed by the version to a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_version_to(
+    &self,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_version_string())
+  }
+
+  // ---------------------------------------------------------------------------
+  // private functions
+  // ---------------------------------------------------------------------------
+
+  //----------------------------------------------------------------------------
+  /// The name followed by the version, either of which may be missing
+  //----------------------------------------------------------------------------
+  fn make_name_line(&self) -> String {
+    let parts: Vec<&str> = [
+      self.name,
+      self.version,
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    parts.join(" ")
+  }
+
+  fn make_print_string_with_about(
+    &self,
+    about_option: Option<&str>,
+  ) -> String {
     let mut app_info: String = String::new();
 
-    if let Some(name) = self.name {
-      app_info.push_str(name);
+    if self.name.is_some() || self.version.is_some() {
+      app_info.push_str(&self.make_name_line());
 
       app_info.push('\n');
     }
@@ -47,7 +260,7 @@ impl AppInfo<'_> {
       app_info.push('\n');
     }
 
-    if let Some(about) = self.about {
+    if let Some(about) = about_option {
       app_info.push_str(about);
 
       app_info.push('\n');
@@ -55,11 +268,4 @@ impl AppInfo<'_> {
 
     app_info
   }
-
-  //----------------------------------------------------------------------------
-  /// Prints the application information
-  //----------------------------------------------------------------------------
-  pub fn print(&self) {
-    print!("{}", self.make_print_string());
-  }
 }
@@ -2,15 +2,17 @@
 //! Unit tests for AppInfo
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-07
-//! - Updated: 2024-06-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use crate::print::build_info::BuildInfo;
+
 use super::*;
 
 const TEST_ABOUT: &str = "TEST_ABOUT";
@@ -23,11 +25,41 @@ const TEST_NAME: &str = "TEST_NAME";
 
 const TEST_APP_INFO: AppInfo = AppInfo {
   about: Some(TEST_ABOUT),
+  authors: &[],
+  bug_report: None,
   contact: Some(TEST_CONTACT),
   copyright: Some(TEST_COPYRIGHT),
+  homepage: None,
+  license: None,
+  long_about: None,
   name: Some(TEST_NAME),
+  version: None,
 };
 
+#[test]
+fn test_make_long_print_string_with_width_0() {
+  let test_app_info: AppInfo = AppInfo {
+    long_about: Some("TEST ABOUT\n\nTEST LONG ABOUT"),
+    ..TEST_APP_INFO
+  };
+
+  let expected: String = format!(
+    "{}\n{}\n{}\nTEST\nABOUT\n\nTEST\nLONG\nABOUT\n",
+    TEST_NAME, TEST_COPYRIGHT, TEST_CONTACT,
+  );
+
+  let actual: String = test_app_info.make_long_print_string_with_width(5);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_long_print_string_with_width_1() {
+  let actual: String = TEST_APP_INFO.make_long_print_string_with_width(5);
+
+  assert_eq!(actual, TEST_APP_INFO.make_print_string());
+}
+
 #[test]
 fn test_make_print_string_0() {
   let expected: String = format!(
@@ -39,3 +71,120 @@ fn test_make_print_string_0() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_make_print_string_1() {
+  let test_app_info: AppInfo = AppInfo {
+    version: Some("1.2.3"),
+    ..TEST_APP_INFO
+  };
+
+  assert!(
+    test_app_info
+      .make_print_string()
+      .starts_with("TEST_NAME 1.2.3\n")
+  );
+}
+
+#[test]
+fn test_make_verbose_version_string_0() {
+  let test_app_info: AppInfo = AppInfo {
+    authors: &[
+      "TEST_AUTHOR_0",
+      "TEST_AUTHOR_1",
+    ],
+    bug_report: Some("TEST_BUG_REPORT"),
+    homepage: Some("TEST_HOMEPAGE"),
+    license: Some("MIT"),
+    version: Some("1.2.3"),
+    ..TEST_APP_INFO
+  };
+
+  let test_build_info: BuildInfo = BuildInfo {
+    features: &["serde"],
+    target: None,
+    version: Some("1.2.3"),
+  };
+
+  let expected: &str = "TEST_NAME 1.2.3\n\
+    authors: TEST_AUTHOR_0, TEST_AUTHOR_1\n\
+    homepage: TEST_HOMEPAGE\n\
+    license: MIT\n\
+    bug reports: TEST_BUG_REPORT\n\
+    crate version: 1.2.3\n\
+    features: serde\n";
+
+  let actual: String =
+    test_app_info.make_verbose_version_string(&test_build_info);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_make_version_string_0() {
+  let test_app_info: AppInfo = AppInfo {
+    version: Some("1.2.3"),
+    ..TEST_APP_INFO
+  };
+
+  assert_eq!(test_app_info.make_version_string(), "TEST_NAME 1.2.3\n");
+
+  assert_eq!(TEST_APP_INFO.make_version_string(), "TEST_NAME\n");
+}
+
+#[test]
+fn test_print_to_0() {
+  let mut buffer: Vec<u8> = Vec::new();
+
+  TEST_APP_INFO.print_to(&mut buffer).unwrap();
+
+  let actual: String = String::from_utf8(buffer).unwrap();
+
+  assert_eq!(actual, TEST_APP_INFO.make_print_string());
+}
+
+#[test]
+fn test_write_to_0() {
+  let mut actual: String = String::new();
+
+  TEST_APP_INFO.write_to(&mut actual).unwrap();
+
+  assert_eq!(actual, TEST_APP_INFO.make_print_string());
+}
+
+#[test]
+fn test_print_version_to_0() {
+  let mut buffer: Vec<u8> = Vec::new();
+
+  TEST_APP_INFO.print_version_to(&mut buffer).unwrap();
+
+  let actual: String = String::from_utf8(buffer).unwrap();
+
+  assert_eq!(actual, "TEST_NAME\n");
+}
+
+#[test]
+fn test_write_verbose_version_to_0() {
+  let test_build_info: BuildInfo = BuildInfo {
+    features: &[],
+    target: Some("TEST_TARGET"),
+    version: None,
+  };
+
+  let mut actual: String = String::new();
+
+  TEST_APP_INFO
+    .write_verbose_version_to(&test_build_info, &mut actual)
+    .unwrap();
+
+  assert_eq!(actual, "TEST_NAME\ntarget: TEST_TARGET\n");
+}
+
+#[test]
+fn test_write_version_to_0() {
+  let mut actual: String = String::new();
+
+  TEST_APP_INFO.write_version_to(&mut actual).unwrap();
+
+  assert_eq!(actual, "TEST_NAME\n");
+}
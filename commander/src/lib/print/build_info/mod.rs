@@ -0,0 +1,51 @@
+//==============================================================================
+//! Module for BuildInfo
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Build data supplied by the application for -\-version -\-verbose
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BuildInfo<'a> {
+  /// The cargo features enabled when the application was built
+  pub features: &'a [&'a str],
+  /// The target triple such as "x86_64-unknown-linux-gnu"
+  pub target: Option<&'a str>,
+  /// The crate version, such as from env!("CARGO_PKG_VERSION")
+  pub version: Option<&'a str>,
+}
+
+impl BuildInfo<'_> {
+  //----------------------------------------------------------------------------
+  /// The build information with one labeled line for each value present
+  //----------------------------------------------------------------------------
+  pub fn make_print_string(&self) -> String {
+    let mut build_info: String = String::new();
+
+    if let Some(version) = self.version {
+      build_info.push_str(&format!("crate version: {version}\n"));
+    }
+
+    if let Some(target) = self.target {
+      build_info.push_str(&format!("target: {target}\n"));
+    }
+
+    if !self.features.is_empty() {
+      build_info.push_str(&format!("features: {}\n", self.features.join(", ")));
+    }
+
+    build_info
+  }
+}
@@ -0,0 +1,37 @@
+//==============================================================================
+//! Unit tests for BuildInfo
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+#[test]
+fn test_make_print_string_0() {
+  let test_build_info: BuildInfo = BuildInfo {
+    features: &[
+      "serde",
+      "terminal_size",
+    ],
+    target: Some("x86_64-unknown-linux-gnu"),
+    version: Some("1.2.3"),
+  };
+
+  let expected: &str = "crate version: 1.2.3\n\
+    target: x86_64-unknown-linux-gnu\n\
+    features: serde, terminal_size\n";
+
+  assert_eq!(test_build_info.make_print_string(), expected);
+}
+
+#[test]
+fn test_make_print_string_1() {
+  assert_eq!(BuildInfo::default().make_print_string(), "");
+}
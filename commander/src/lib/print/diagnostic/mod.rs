@@ -0,0 +1,382 @@
+//==============================================================================
+//! Module for diagnostics that point to the option in the command line
+//!
+//! - Reprints the command line with the arguments quoted as for a shell
+//! - Underlines the offending option or value with carets
+//! - Adds an optional hint line
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::fmt;
+use std::io;
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
+use crate::parse::parse_option_error::ParseOptionError;
+use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_results::ParseResults;
+use crate::parse::parse_rule_error::ParseRuleError;
+
+use super::wrap;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Makes a diagnostic that underlines where the option was found
+///
+/// - The args are all of the command-line arguments including the command
+/// - Underlines the value instead of the option name if underline_value is
+///   true and the argument has a value after an equals sign
+/// - Returns only the message line if the argument index is out of range
+//------------------------------------------------------------------------------
+pub fn format_diagnostic(
+  args: &[String],
+  found: &ParseFound,
+  underline_value: bool,
+  message: &str,
+  hint: Option<&str>,
+) -> String {
+  let mut diagnostic: String = format!("error: {message}\n");
+
+  let arg_index: usize = found.get_arg_index();
+
+  if let Some(arg) = args.get(arg_index) {
+    let arg_span: Range<usize> = make_arg_span(arg, found, underline_value);
+
+    let mut command_line: String = String::new();
+
+    let mut caret_start: usize = 0;
+
+    let mut caret_end: usize = 0;
+
+    for (index, arg) in args.iter().enumerate() {
+      if index > 0 {
+        command_line.push(' ');
+      }
+
+      let column: usize = wrap::display_width(&command_line);
+
+      let (quoted_arg, offsets): (String, Vec<usize>) =
+        shell_quote_with_offsets(arg);
+
+      if index == arg_index {
+        caret_start = column + offsets[arg_span.start];
+
+        caret_end = column + offsets[arg_span.end];
+      }
+
+      command_line.push_str(&quoted_arg);
+    }
+
+    let caret_count: usize = caret_end.saturating_sub(caret_start).max(1);
+
+    diagnostic.push_str(&format!(
+      "  {}\n  {}{}\n",
+      command_line,
+      " ".repeat(caret_start),
+      "^".repeat(caret_count),
+    ));
+  }
+
+  if let Some(hint) = hint {
+    diagnostic.push_str(&format!("  hint: {hint}\n"));
+  }
+
+  diagnostic
+}
+
+//------------------------------------------------------------------------------
+/// Makes a diagnostic for an option with a parsing error
+///
+/// - Underlines the value for an invalid or verboten value
+/// - Hints at the expected form of the option
+/// - Returns an empty string if the option does not have an error
+//------------------------------------------------------------------------------
+pub fn format_error_diagnostic(
+  args: &[String],
+  error_option: &ParseOutput,
+) -> String {
+  let parse_option_error_option: Option<ParseOptionError> =
+    error_option.to_error();
+
+  let Some(parse_option_error) = parse_option_error_option else {
+    return String::new();
+  };
+
+  let underline_value: bool = matches!(
+    parse_option_error.error,
+    ParseError::InvalidValue | ParseError::VerbotenValuePresent
+  );
+
+  let hint: String = format!("expected {}", parse_option_error.expected);
+
+  format_diagnostic(
+    args,
+    &parse_option_error.found,
+    underline_value,
+    &parse_option_error.to_string(),
+    Some(&hint),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Makes the diagnostics for the problems in the parse results
+///
+/// - Lists the options with parsing errors, then the unknown options, and
+///   then the errors found after the parse
+/// - Reports an unknown option with an error such as "-\-unknown=" only once,
+///   as an unknown option
+/// - Returns an empty string if there are no problems
+//------------------------------------------------------------------------------
+pub fn format_report_diagnostics(
+  args: &[String],
+  parse_results: &ParseResults,
+) -> String {
+  let mut diagnostics: String = String::new();
+
+  for parse_output in &parse_results.parse_outputs {
+    if parse_output.error.is_some() && parse_output.known.is_some() {
+      diagnostics.push_str(&format_error_diagnostic(args, parse_output));
+    }
+  }
+
+  for parse_output in &parse_results.parse_outputs {
+    if parse_output.known.is_none() {
+      diagnostics.push_str(&format_unknown_diagnostic(args, parse_output));
+    }
+  }
+
+  for rule_error in &parse_results.rule_errors {
+    diagnostics.push_str(&format_rule_diagnostic(args, rule_error));
+  }
+
+  diagnostics
+}
+
+//------------------------------------------------------------------------------
+/// Makes a diagnostic for an error found after a full parse
+///
+/// - Underlines the first option involved in the error, if any
+//------------------------------------------------------------------------------
+pub fn format_rule_diagnostic(
+  args: &[String],
+  rule_error: &ParseRuleError,
+) -> String {
+  let message: String = rule_error.to_string();
+
+  let found_option: Option<&ParseFound> = match rule_error {
+    ParseRuleError::Conflict {
+      found,
+      ..
+    }
+    | ParseRuleError::RequirementMissing {
+      found,
+      ..
+    } => Some(found),
+    ParseRuleError::GroupMultiple {
+      founds,
+      ..
+    } => founds.first(),
+    _ => None,
+  };
+
+  match found_option {
+    Some(found) => format_diagnostic(args, found, false, &message, None),
+    None => format!("error: {message}\n"),
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Makes a diagnostic for an unknown option
+///
+/// - Hints at the similar known options, if any
+//------------------------------------------------------------------------------
+pub fn format_unknown_diagnostic(
+  args: &[String],
+  unknown_option: &ParseOutput,
+) -> String {
+  let message: String = format!("Unknown option at {}", unknown_option.found);
+
+  let hint_option: Option<String> = if unknown_option.suggestions.is_empty() {
+    None
+  } else {
+    Some(format!(
+      "did you mean {}?",
+      unknown_option.suggestions.join(" or ")
+    ))
+  };
+
+  format_diagnostic(
+    args,
+    &unknown_option.found,
+    false,
+    &message,
+    hint_option.as_deref(),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Prints the diagnostics for the problems in the parse results to standard
+/// error.
+///
+/// - Returns true if there were any problems to report
+//------------------------------------------------------------------------------
+pub fn print_report_diagnostics(
+  args: &[String],
+  parse_results: &ParseResults,
+) -> bool {
+  let diagnostics: String = format_report_diagnostics(args, parse_results);
+
+  eprint!("{diagnostics}");
+
+  !diagnostics.is_empty()
+}
+
+//------------------------------------------------------------------------------
+/// Prints the diagnostics for the problems in the parse results to an output
+/// stream.
+///
+/// - Returns true if there were any problems to report
+//------------------------------------------------------------------------------
+pub fn print_report_diagnostics_to(
+  args: &[String],
+  parse_results: &ParseResults,
+  writer: &mut dyn io::Write,
+) -> io::Result<bool> {
+  let diagnostics: String = format_report_diagnostics(args, parse_results);
+
+  writer.write_all(diagnostics.as_bytes())?;
+
+  Ok(!diagnostics.is_empty())
+}
+
+//------------------------------------------------------------------------------
+/// Quotes an argument with single quotes if a shell would need it quoted
+//------------------------------------------------------------------------------
+pub fn shell_quote(arg: &str) -> String {
+  shell_quote_with_offsets(arg).0
+}
+
+//------------------------------------------------------------------------------
+/// Writes the diagnostics for the problems in the parse results to a string or
+/// formatter.
+///
+/// - Returns true if there were any problems to report
+//------------------------------------------------------------------------------
+pub fn write_report_diagnostics_to(
+  args: &[String],
+  parse_results: &ParseResults,
+  writer: &mut dyn fmt::Write,
+) -> Result<bool, fmt::Error> {
+  let diagnostics: String = format_report_diagnostics(args, parse_results);
+
+  writer.write_str(&diagnostics)?;
+
+  Ok(!diagnostics.is_empty())
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// The character range within the argument to underline
+//------------------------------------------------------------------------------
+fn make_arg_span(
+  arg: &str,
+  found: &ParseFound,
+  underline_value: bool,
+) -> Range<usize> {
+  let arg_len: usize = arg.chars().count();
+
+  let equals_index_option: Option<usize> =
+    arg.chars().position(|c: char| c == '=');
+
+  if underline_value
+    && let Some(equals_index) = equals_index_option
+    && equals_index + 1 < arg_len
+  {
+    return equals_index + 1..arg_len;
+  }
+
+  let span: Range<usize> = match found {
+    ParseFound::Implied {
+      ..
+    } => 0..arg_len,
+    ParseFound::Long {
+      name_long,
+      ..
+    } => 0..2 + name_long.chars().count(),
+    ParseFound::Short {
+      char_index,
+      ..
+    } => 1 + char_index..2 + char_index,
+  };
+
+  span.start.min(arg_len)..span.end.min(arg_len)
+}
+
+//------------------------------------------------------------------------------
+/// Quotes the argument and maps each character index to its quoted column
+///
+/// - The columns are display widths so that wide characters line up
+/// - The offsets include one more entry for the end of the argument
+//------------------------------------------------------------------------------
+fn shell_quote_with_offsets(arg: &str) -> (String, Vec<usize>) {
+  let needs_quotes: bool = arg.is_empty()
+    || arg
+      .chars()
+      .any(|c: char| !(c.is_alphanumeric() || "%+,-./:=@_".contains(c)));
+
+  if !needs_quotes {
+    let mut offsets: Vec<usize> = vec![0];
+
+    let mut column: usize = 0;
+
+    for c in arg.chars() {
+      column += UnicodeWidthChar::width(c).unwrap_or(0);
+
+      offsets.push(column);
+    }
+
+    return (arg.to_string(), offsets);
+  }
+
+  let mut quoted: String = String::from("'");
+
+  let mut offsets: Vec<usize> = Vec::new();
+
+  let mut column: usize = 1;
+
+  for c in arg.chars() {
+    offsets.push(column);
+
+    if c == '\'' {
+      quoted.push_str("'\\''");
+
+      column += 4;
+    } else {
+      quoted.push(c);
+
+      column += UnicodeWidthChar::width(c).unwrap_or(0);
+    }
+  }
+
+  offsets.push(column);
+
+  quoted.push('\'');
+
+  (quoted, offsets)
+}
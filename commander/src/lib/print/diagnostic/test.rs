@@ -0,0 +1,186 @@
+//==============================================================================
+//! Unit tests for module diagnostic
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::parse_input::ParseInput;
+
+use super::*;
+
+fn make_args(args: &[&str]) -> Vec<String> {
+  args.iter().map(|arg: &&str| arg.to_string()).collect()
+}
+
+#[test]
+fn test_format_error_diagnostic_0() {
+  let test_args: Vec<String> = make_args(&[
+    "example",
+    "-qi=invalid",
+  ]);
+
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::InvalidValue),
+    found: ParseFound::Short {
+      arg_index: 1,
+      char_index: 1,
+      name_short: 'i',
+    },
+    known: Some("interactive".to_string()),
+    suggestions: Vec::new(),
+    value: Some("invalid".to_string()),
+  };
+
+  let expected: &str = "error: Invalid value for option at argument index 1 \
+    character index 1: 'i'\n  \
+    example -qi=invalid\n  \
+    \x20           ^^^^^^^\n  \
+    hint: expected -i with a valid value\n";
+
+  let actual: String = format_error_diagnostic(&test_args, &test_parse_output);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_error_diagnostic_1() {
+  let test_args: Vec<String> = make_args(&[
+    "example", "--name",
+  ]);
+
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::RequiredValueMissing),
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "name".to_string(),
+    },
+    known: Some("name".to_string()),
+    suggestions: Vec::new(),
+    value: None,
+  };
+
+  let expected: &str = "error: Required value missing for option at \
+    argument index 1: \"name\"\n  \
+    example --name\n  \
+    \x20       ^^^^^^\n  \
+    hint: expected --name=<VALUE>\n";
+
+  let actual: String = format_error_diagnostic(&test_args, &test_parse_output);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_unknown_diagnostic_0() {
+  let test_args: Vec<String> = make_args(&[
+    "example",
+    "hello world",
+    "--nmae=David",
+  ]);
+
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 2,
+      name_long: "nmae".to_string(),
+    },
+    known: None,
+    suggestions: vec!["--name".to_string()],
+    value: Some("David".to_string()),
+  };
+
+  let expected: &str = "error: Unknown option at argument index 2: \
+    \"nmae\"\n  \
+    example 'hello world' --nmae=David\n  \
+    \x20                     ^^^^^^\n  \
+    hint: did you mean --name?\n";
+
+  let actual: String =
+    format_unknown_diagnostic(&test_args, &test_parse_output);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_rule_diagnostic_0() {
+  let test_rule_error: ParseRuleError = ParseRuleError::MissingOptions {
+    ids: vec!["TEST_ID".to_string()],
+    names: vec!["--TEST".to_string()],
+  };
+
+  let expected: &str = "error: Missing required options: --TEST\n";
+
+  let actual: String = format_rule_diagnostic(&[], &test_rule_error);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_unknown_diagnostic_1() {
+  let test_args: Vec<String> = make_args(&[
+    "例", "--名前",
+  ]);
+
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "名前".to_string(),
+    },
+    known: None,
+    suggestions: Vec::new(),
+    value: None,
+  };
+
+  let expected: &str = "error: Unknown option at argument index 1: \
+    \"名前\"\n  \
+    例 --名前\n  \
+    \x20  ^^^^^^\n";
+
+  let actual: String =
+    format_unknown_diagnostic(&test_args, &test_parse_output);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_write_report_diagnostics_to_0() {
+  let test_args: Vec<String> = make_args(&[
+    "example",
+    "--unknown=",
+  ]);
+
+  let test_parse_results: ParseResults =
+    ParseInput::new(&test_args, &[]).parse();
+
+  let expected: &str = "error: Unknown option at argument index 1: \
+    \"unknown\"\n  \
+    example --unknown=\n  \
+    \x20       ^^^^^^^^^\n";
+
+  let mut actual: String = String::new();
+
+  let reported: bool =
+    write_report_diagnostics_to(&test_args, &test_parse_results, &mut actual)
+      .unwrap();
+
+  assert!(reported);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_shell_quote_0() {
+  assert_eq!(shell_quote("--name=David"), "--name=David");
+
+  assert_eq!(shell_quote(""), "''");
+
+  assert_eq!(shell_quote("it's"), "'it'\\''s'");
+}
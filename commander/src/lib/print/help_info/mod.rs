@@ -2,17 +2,28 @@
 //! Module for HelpInfo
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-06-08
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use std::fmt;
+use std::io;
+
+use crate::parse::parse_found::ParseFound;
+use crate::parse::parse_group::ParseGroup;
+use crate::parse::parse_option_config::ParseOptionConfig;
+use crate::parse::value_usage::ValueUsage;
+
 use super::app_info::AppInfo;
+use super::help_item::HelpItem;
 use super::option_config::OptionConfig;
+use super::version_option;
+use super::wrap;
 
 #[cfg(test)]
 mod test;
@@ -23,27 +34,562 @@ mod test;
 #[derive(Debug)]
 pub struct HelpInfo<'a> {
   pub app_info: &'a AppInfo<'a>,
+  /// The options, shown with the -\-version and -\-verbose options added if
+  /// the application has a version
   pub arg_options: &'a [OptionConfig<'a>],
+  /// The name used to run the application, shown in the usage line
+  pub command_name: Option<&'a str>,
+  /// The groups of options, shown together in the usage line
+  pub parse_groups: &'a [ParseGroup<'a>],
+  /// The positional arguments shown after the options in the usage line,
+  /// such as "<FILE>...", and in the ARGUMENTS section
+  pub positionals: &'a [HelpItem<'a>],
+  /// Sorts the options alphabetically by name within each section, before
+  /// the display order is applied
+  pub sort_options: bool,
+  /// The subcommands shown in the COMMANDS section
+  pub subcommands: &'a [HelpItem<'a>],
+  /// A hand-written usage line shown instead of the generated one
+  pub usage: Option<&'a str>,
+  /// Shows the -\-version and -\-verbose options after the options if they are
+  /// not already in the options, as from version_option::add_version_options
+  pub version_options: bool,
 }
 
 impl HelpInfo<'_> {
-  pub fn make_print_string(&self) -> String {
-    let mut print_string: String = String::from("\n");
+  //----------------------------------------------------------------------------
+  /// The descriptions including the hidden options, as for -\-help-all
+  ///
+  /// - Reads COLUMNS or queries the terminal, see wrap::get_terminal_width
+  /// - Use make_all_print_string_with_width for output that must not vary
+  //----------------------------------------------------------------------------
+  pub fn make_all_print_string(&self) -> String {
+    self.make_all_print_string_with_width(wrap::get_terminal_width())
+  }
 
-    print_string.push_str(&self.app_info.make_print_string());
+  //----------------------------------------------------------------------------
+  /// The descriptions including the hidden options wrapped to a fixed width
+  ///
+  /// - The hidden options are also shown in the usage line
+  //----------------------------------------------------------------------------
+  pub fn make_all_print_string_with_width(
+    &self,
+    width: usize,
+  ) -> String {
+    self.make_table_print_string(width, true)
+  }
+
+  //----------------------------------------------------------------------------
+  /// The long application and options descriptions shown for -\-help
+  ///
+  /// - Reads COLUMNS or queries the terminal, see wrap::get_terminal_width
+  /// - Use make_long_print_string_with_width for output that must not vary
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string(&self) -> String {
+    self.make_long_print_string_with_width(wrap::get_terminal_width())
+  }
 
-    print_string.push_str("\nOPTIONS:\n");
+  //----------------------------------------------------------------------------
+  /// The long application and options descriptions wrapped to a fixed width
+  ///
+  /// - Shows the long about text instead of the about text if there is one
+  /// - Shows each option on its own line followed by its brief and long
+  ///   descriptions
+  /// - See make_print_string_with_width for the sections
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string_with_width(
+    &self,
+    width: usize,
+  ) -> String {
+    let mut print_string: String = String::from("\n");
 
     print_string
-      .push_str(&OptionConfig::make_print_string_for_slice(self.arg_options));
+      .push_str(&self.app_info.make_long_print_string_with_width(width));
+
+    print_string.push_str("\nUSAGE:\n");
+
+    print_string.push_str(&self.make_usage_string());
+
+    print_string.push_str(&self.make_items_string(width));
+
+    for (heading, arg_options) in self.make_option_sections(false) {
+      print_string.push_str(&format!("\n{heading}:\n"));
+
+      print_string.push_str(
+        &OptionConfig::make_long_print_string_for_slice_with_width(
+          &arg_options,
+          width,
+        ),
+      );
+    }
 
     print_string
   }
 
+  //----------------------------------------------------------------------------
+  /// The application and options descriptions wrapped to the terminal width
+  ///
+  /// - Reads COLUMNS or queries the terminal, see wrap::get_terminal_width
+  /// - Use make_print_string_with_width for output that must not vary
+  //----------------------------------------------------------------------------
+  pub fn make_print_string(&self) -> String {
+    self.make_print_string_with_width(wrap::get_terminal_width())
+  }
+
+  //----------------------------------------------------------------------------
+  /// The application and options descriptions wrapped to a fixed width
+  ///
+  /// - The subcommands and positional arguments are shown in the COMMANDS
+  ///   and ARGUMENTS sections if any
+  /// - Options without a heading are shown in the OPTIONS section followed
+  ///   by a section for each heading in the order first shown
+  //----------------------------------------------------------------------------
+  pub fn make_print_string_with_width(
+    &self,
+    width: usize,
+  ) -> String {
+    self.make_table_print_string(width, false)
+  }
+
+  //----------------------------------------------------------------------------
+  /// The usage line with the command name followed by the options
+  ///
+  /// - Returns the hand-written usage line instead if there is one
+  /// - Required options are shown without brackets
+  /// - Options with values are shown with a value placeholder
+  /// - Optional short flags without values are shown together as in [-hq]
+  ///   where the first one would be
+  /// - Options in a group are shown together where the first one would be
+  /// - The positional arguments are shown after the options
+  /// - Hidden options are not shown
+  //----------------------------------------------------------------------------
+  pub fn make_usage_string(&self) -> String {
+    self.make_usage_string_with_hidden(false)
+  }
+
   //----------------------------------------------------------------------------
   /// Prints the application and options descriptions
   //----------------------------------------------------------------------------
   pub fn print(&self) {
     print!("{}", self.make_print_string());
   }
+
+  //----------------------------------------------------------------------------
+  /// Prints the descriptions including the hidden options
+  //----------------------------------------------------------------------------
+  pub fn print_all(&self) {
+    self
+      .print_all_to(&mut io::stdout())
+      .expect("failed printing to stdout");
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the descriptions including the hidden options to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_all_to(
+    &self,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_all_print_string().as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the long descriptions for -\-help or the compact table for -h
+  ///
+  /// - The found is where the help option was found in the arguments
+  //----------------------------------------------------------------------------
+  pub fn print_for(
+    &self,
+    found: &ParseFound,
+  ) {
+    self
+      .print_for_to(found, &mut io::stdout())
+      .expect("failed printing to stdout");
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the long descriptions for -\-help or the compact table for -h to
+  /// an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_for_to(
+    &self,
+    found: &ParseFound,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    match found {
+      ParseFound::Long {
+        ..
+      } => self.print_long_to(writer),
+      _ => self.print_to(writer),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the long application and options descriptions
+  //----------------------------------------------------------------------------
+  pub fn print_long(&self) {
+    print!("{}", self.make_long_print_string());
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the long application and options descriptions to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_long_to(
+    &self,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_long_print_string().as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Prints the application and options descriptions to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_to(
+    &self,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(self.make_print_string().as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the descriptions including the hidden options to a string or
+  /// formatter
+  //----------------------------------------------------------------------------
+  pub fn write_all_to(
+    &self,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_all_print_string())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the long descriptions for -\-help or the compact table for -h to a
+  /// string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_for_to(
+    &self,
+    found: &ParseFound,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    match found {
+      ParseFound::Long {
+        ..
+      } => self.write_long_to(writer),
+      _ => self.write_to(writer),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the application and options descriptions to a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_to(
+    &self,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_print_string())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes the long application and options descriptions to a string or
+  /// formatter
+  //----------------------------------------------------------------------------
+  pub fn write_long_to(
+    &self,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&self.make_long_print_string())
+  }
+
+  // ---------------------------------------------------------------------------
+  // private functions
+  // ---------------------------------------------------------------------------
+
+  //----------------------------------------------------------------------------
+  /// The options with the -\-version and -\-verbose options added if
+  /// version_options is true
+  ///
+  /// - See version_option::add_version_options
+  //----------------------------------------------------------------------------
+  fn get_arg_options(&self) -> Vec<OptionConfig<'_>> {
+    if !self.version_options {
+      return self.arg_options.to_vec();
+    }
+
+    version_option::add_version_options(self.arg_options)
+  }
+
+  //----------------------------------------------------------------------------
+  /// The short name of an optional flag without a value that is not in a group
+  //----------------------------------------------------------------------------
+  fn get_flag_name_short(
+    &self,
+    arg_option: &OptionConfig,
+    include_hidden: bool,
+  ) -> Option<char> {
+    let parse_option_config: &ParseOptionConfig =
+      &arg_option.parse_option_config;
+
+    if (!include_hidden && parse_option_config.hidden)
+      || parse_option_config.required
+      || parse_option_config.value_usage != ValueUsage::Verboten
+      || self.parse_groups.iter().any(|parse_group: &ParseGroup| {
+        parse_group.ids.contains(&parse_option_config.id)
+      })
+    {
+      return None;
+    }
+
+    parse_option_config.name.get_name_short()
+  }
+
+  //----------------------------------------------------------------------------
+  /// The optional short flags without values shown together as in [-hq]
+  //----------------------------------------------------------------------------
+  fn make_flags_usage_item(
+    &self,
+    include_hidden: bool,
+  ) -> String {
+    let flags: String = self
+      .get_arg_options()
+      .iter()
+      .filter_map(|arg_option: &OptionConfig| {
+        self.get_flag_name_short(arg_option, include_hidden)
+      })
+      .collect();
+
+    format!("[-{flags}]")
+  }
+
+  fn make_group_usage_item(
+    &self,
+    parse_group: &ParseGroup,
+    include_hidden: bool,
+  ) -> String {
+    let usage_names: Vec<String> = parse_group
+      .ids
+      .iter()
+      .filter_map(|id: &&str| {
+        self.arg_options.iter().find(|arg_option: &&OptionConfig| {
+          arg_option.parse_option_config.id == *id
+        })
+      })
+      .filter(|arg_option: &&OptionConfig| {
+        include_hidden || !arg_option.parse_option_config.hidden
+      })
+      .map(|arg_option: &OptionConfig| {
+        format!(
+          "{}{}",
+          arg_option.make_usage_name(),
+          arg_option.make_usage_value()
+        )
+      })
+      .collect();
+
+    let usage_names_string: String = usage_names.join(" | ");
+
+    if parse_group.group_usage.allows_none() {
+      format!("[{usage_names_string}]")
+    } else {
+      format!("({usage_names_string})")
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// The COMMANDS and ARGUMENTS sections, if any
+  //----------------------------------------------------------------------------
+  fn make_items_string(
+    &self,
+    width: usize,
+  ) -> String {
+    let mut items_string: String = String::new();
+
+    for (heading, help_items) in [
+      ("COMMANDS", self.subcommands),
+      ("ARGUMENTS", self.positionals),
+    ] {
+      if help_items.is_empty() {
+        continue;
+      }
+
+      items_string.push_str(&format!("\n{heading}:\n"));
+
+      items_string.push_str(&HelpItem::make_print_string_for_slice_with_width(
+        help_items, width,
+      ));
+    }
+
+    items_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// The options grouped into sections by heading, in display order
+  ///
+  /// - The options without a heading are first, under OPTIONS
+  /// - The other sections are in the order their first option is shown
+  /// - Within a section, the options with a display order come first
+  /// - Hidden options are left out unless include_hidden is true
+  //----------------------------------------------------------------------------
+  fn make_option_sections(
+    &self,
+    include_hidden: bool,
+  ) -> Vec<(&str, Vec<OptionConfig<'_>>)> {
+    let mut arg_options: Vec<OptionConfig> = self
+      .get_arg_options()
+      .into_iter()
+      .filter(|arg_option: &OptionConfig| {
+        include_hidden || !arg_option.parse_option_config.hidden
+      })
+      .collect();
+
+    if self.sort_options {
+      arg_options.sort_by_key(|arg_option: &OptionConfig| {
+        let parse_option_config: &ParseOptionConfig =
+          &arg_option.parse_option_config;
+
+        parse_option_config.name.get_name_long().map_or_else(
+          || {
+            parse_option_config
+              .name
+              .get_name_short()
+              .unwrap_or_default()
+              .to_string()
+          },
+          |name_long: &str| name_long.to_string(),
+        )
+      });
+    }
+
+    arg_options.sort_by_key(|arg_option: &OptionConfig| {
+      arg_option.display_order.unwrap_or(usize::MAX)
+    });
+
+    let mut sections: Vec<(&str, Vec<OptionConfig>)> =
+      vec![("OPTIONS", Vec::new())];
+
+    for arg_option in arg_options {
+      let heading: &str = arg_option.heading.unwrap_or("OPTIONS");
+
+      let section_index: usize = match sections
+        .iter()
+        .position(|(section_heading, _)| *section_heading == heading)
+      {
+        Some(section_index) => section_index,
+        None => {
+          sections.push((heading, Vec::new()));
+
+          sections.len() - 1
+        },
+      };
+
+      sections[section_index].1.push(arg_option);
+    }
+
+    if sections.len() > 1 && sections[0].1.is_empty() {
+      sections.remove(0);
+    }
+
+    sections
+  }
+
+  //----------------------------------------------------------------------------
+  /// The descriptions with the options in a compact table
+  //----------------------------------------------------------------------------
+  fn make_table_print_string(
+    &self,
+    width: usize,
+    include_hidden: bool,
+  ) -> String {
+    let mut print_string: String = String::from("\n");
+
+    print_string.push_str(&self.app_info.make_print_string());
+
+    print_string.push_str("\nUSAGE:\n");
+
+    print_string.push_str(&self.make_usage_string_with_hidden(include_hidden));
+
+    print_string.push_str(&self.make_items_string(width));
+
+    for (heading, arg_options) in self.make_option_sections(include_hidden) {
+      print_string.push_str(&format!("\n{heading}:\n"));
+
+      print_string.push_str(
+        &OptionConfig::make_print_string_for_slice_with_width(
+          &arg_options,
+          width,
+        ),
+      );
+    }
+
+    print_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// The usage line, including the hidden options if include_hidden is true
+  //----------------------------------------------------------------------------
+  fn make_usage_string_with_hidden(
+    &self,
+    include_hidden: bool,
+  ) -> String {
+    if let Some(usage) = self.usage {
+      return format!("  {usage}\n");
+    }
+
+    let mut usage_items: Vec<String> = Vec::new();
+
+    if let Some(command_name) = self.command_name {
+      usage_items.push(command_name.to_string());
+    }
+
+    let mut shown_group_ids: Vec<&str> = Vec::new();
+
+    let mut shown_flags: bool = false;
+
+    for arg_option in &self.get_arg_options() {
+      if !include_hidden && arg_option.parse_option_config.hidden {
+        continue;
+      }
+
+      let id: &str = arg_option.parse_option_config.id;
+
+      let parse_group_option: Option<&ParseGroup> = self
+        .parse_groups
+        .iter()
+        .find(|parse_group: &&ParseGroup| parse_group.ids.contains(&id));
+
+      if let Some(parse_group) = parse_group_option {
+        if !shown_group_ids.contains(&parse_group.id) {
+          shown_group_ids.push(parse_group.id);
+
+          usage_items
+            .push(self.make_group_usage_item(parse_group, include_hidden));
+        }
+
+        continue;
+      }
+
+      if self
+        .get_flag_name_short(arg_option, include_hidden)
+        .is_none()
+      {
+        usage_items.push(arg_option.make_usage_item());
+
+        continue;
+      }
+
+      if !shown_flags {
+        shown_flags = true;
+
+        usage_items.push(self.make_flags_usage_item(include_hidden));
+      }
+    }
+
+    usage_items.extend(
+      self
+        .positionals
+        .iter()
+        .map(|positional: &HelpItem| positional.name.to_string()),
+    );
+
+    format!("  {}\n", usage_items.join(" "))
+  }
 }
@@ -2,52 +2,178 @@
 //! Unit tests for HelpInfo
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-08
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::group_usage::GroupUsage;
 use crate::parse::parse_option_config::ParseOptionConfig;
 use crate::parse::parse_option_name::ParseOptionName;
 use crate::parse::value_usage::ValueUsage;
+use crate::print::help_item::HelpItem;
 
 use super::*;
 
 const TEST_APP_INFO_0: AppInfo = AppInfo {
   about: Some("TEST_ABOUT_0"),
+  authors: &[],
+  bug_report: None,
   contact: Some("TEST_CONTACT_0"),
   copyright: Some("TEST_COPYRIGHT_0"),
+  homepage: None,
+  license: None,
+  long_about: None,
   name: Some("TEST_NAME_0"),
+  version: None,
 };
 
 const ARG_OPTION_TEST_0: OptionConfig = OptionConfig {
   brief_description: Some("TEST_BRIEF_DESCRIPTION_0"),
+  display_order: None,
+  heading: None,
+  long_description: None,
   parse_option_config: ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
     id: "TEST_ID_0",
     name: ParseOptionName::Both {
       name_long: "TEST_NAME_LONG_0",
       name_short: '0',
     },
+    replacement: None,
+    required: true,
     value_usage: ValueUsage::Required,
   },
+  value_name: Some("NAME"),
 };
 
 const ARG_OPTION_TEST_1: OptionConfig = OptionConfig {
   brief_description: Some("TEST_BRIEF_DESCRIPTION_1"),
+  display_order: None,
+  heading: None,
+  long_description: None,
   parse_option_config: ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
     id: "TEST_ID_1",
     name: ParseOptionName::Both {
       name_long: "TEST_NAME_LONG_1",
       name_short: '1',
     },
+    replacement: None,
+    required: false,
     value_usage: ValueUsage::Verboten,
   },
+  value_name: None,
 };
 
+#[test]
+fn test_make_all_print_string_with_width_0() {
+  const TEST_OPTION_CONFIGS: &[OptionConfig] = &[
+    OptionConfig::long("debug")
+      .short('d')
+      .hidden()
+      .help("Debug"),
+    OptionConfig::long("quiet").short('q').help("Quiet"),
+  ];
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: TEST_OPTION_CONFIGS,
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  const EXPECTED_ALL: &str = "USAGE:\n  \
+      example [-dq]\n\
+    \n\
+    OPTIONS:\n  \
+      -d, --debug  Debug\n  \
+      -q, --quiet  Quiet\n";
+
+  const EXPECTED: &str = "USAGE:\n  \
+      example [-q]\n\
+    \n\
+    OPTIONS:\n  \
+      -q, --quiet  Quiet\n";
+
+  assert!(
+    test_help_info
+      .make_all_print_string_with_width(80)
+      .ends_with(EXPECTED_ALL)
+  );
+
+  assert!(
+    test_help_info
+      .make_print_string_with_width(80)
+      .ends_with(EXPECTED)
+  );
+
+  assert!(
+    !test_help_info
+      .make_long_print_string_with_width(80)
+      .contains("--debug")
+  );
+}
+
+#[test]
+fn test_make_long_print_string_with_width_0() {
+  let test_app_info: AppInfo = AppInfo {
+    long_about: Some("TEST_ABOUT_0\n\nTEST_LONG_ABOUT_0"),
+    ..TEST_APP_INFO_0
+  };
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &test_app_info,
+    arg_options: &[
+      ARG_OPTION_TEST_0.long_help("TEST_LONG_DESCRIPTION_0"),
+      ARG_OPTION_TEST_1,
+    ],
+    command_name: Some("TEST_COMMAND_NAME_0"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  const EXPECTED: &str = "\n\
+    TEST_NAME_0\n\
+    TEST_COPYRIGHT_0\n\
+    TEST_CONTACT_0\n\
+    TEST_ABOUT_0\n\
+    \n\
+    TEST_LONG_ABOUT_0\n\
+    \n\
+    USAGE:\n  \
+      TEST_COMMAND_NAME_0 -0=NAME [-1]\n\
+    \n\
+    OPTIONS:\n  \
+      -0, --TEST_NAME_LONG_0=<NAME>\n        \
+      TEST_BRIEF_DESCRIPTION_0\n\n        \
+      TEST_LONG_DESCRIPTION_0\n\n  \
+      -1, --TEST_NAME_LONG_1\n        \
+      TEST_BRIEF_DESCRIPTION_1\n";
+
+  let actual: String = test_help_info.make_long_print_string_with_width(80);
+
+  assert_eq!(actual, EXPECTED);
+}
+
 #[test]
 fn test_make_print_string_0() {
   let test_help_info: HelpInfo<'_> = HelpInfo {
@@ -56,6 +182,13 @@ fn test_make_print_string_0() {
       ARG_OPTION_TEST_0,
       ARG_OPTION_TEST_1,
     ],
+    command_name: Some("TEST_COMMAND_NAME_0"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
   };
 
   const EXPECTED: &str = "\n\
@@ -64,11 +197,363 @@ fn test_make_print_string_0() {
     TEST_CONTACT_0\n\
     TEST_ABOUT_0\n\
     \n\
+    USAGE:\n  \
+      TEST_COMMAND_NAME_0 -0=NAME [-1]\n\
+    \n\
+    OPTIONS:\n  \
+      -0, --TEST_NAME_LONG_0=<NAME>  TEST_BRIEF_DESCRIPTION_0\n  \
+      -1, --TEST_NAME_LONG_1         TEST_BRIEF_DESCRIPTION_1\n";
+
+  let actual: String = test_help_info.make_print_string_with_width(80);
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_with_width_0() {
+  const TEST_OPTION_CONFIGS: &[OptionConfig] = &[
+    OptionConfig::long("output")
+      .heading("Output")
+      .help("Output file"),
+    OptionConfig::long("input")
+      .heading("Input")
+      .help("Input file"),
+    OptionConfig::long("help").short('h').help("Show help"),
+    OptionConfig::long("format")
+      .heading("Output")
+      .display_order(0),
+  ];
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: TEST_OPTION_CONFIGS,
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[HelpItem::new("<FILE>").help("The file to read")],
+    sort_options: false,
+    subcommands: &[
+      HelpItem::new("build").help("Compile"),
+      HelpItem::new("run"),
+    ],
+    usage: Some("example [OPTIONS] <COMMAND> <FILE>"),
+    version_options: false,
+  };
+
+  const EXPECTED: &str = "\n\
+    TEST_NAME_0\n\
+    TEST_COPYRIGHT_0\n\
+    TEST_CONTACT_0\n\
+    TEST_ABOUT_0\n\
+    \n\
+    USAGE:\n  \
+      example [OPTIONS] <COMMAND> <FILE>\n\
+    \n\
+    COMMANDS:\n  \
+      build  Compile\n  \
+      run\n\
+    \n\
+    ARGUMENTS:\n  \
+      <FILE>  The file to read\n\
+    \n\
+    OPTIONS:\n  \
+      -h, --help  Show help\n\
+    \n\
+    Output:\n  \
+      --format  \n  \
+      --output  Output file\n\
+    \n\
+    Input:\n  \
+      --input  Input file\n";
+
+  let actual: String = test_help_info.make_print_string_with_width(80);
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_with_width_1() {
+  const TEST_OPTION_CONFIGS: &[OptionConfig] = &[
+    OptionConfig::long("quiet").help("Quiet"),
+    OptionConfig::long("help").help("Help"),
+    OptionConfig::long("verbose")
+      .help("Verbose")
+      .display_order(0),
+    OptionConfig::long("name").help("Name"),
+  ];
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: TEST_OPTION_CONFIGS,
+    command_name: None,
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: true,
+    subcommands: &[],
+    usage: Some("example"),
+    version_options: false,
+  };
+
+  const EXPECTED: &str = "\n\
+    OPTIONS:\n  \
+      --verbose  Verbose\n  \
+      --help     Help\n  \
+      --name     Name\n  \
+      --quiet    Quiet\n";
+
+  let actual: String = test_help_info.make_print_string_with_width(80);
+
+  assert!(actual.ends_with(EXPECTED));
+}
+
+#[test]
+fn test_make_print_string_with_width_2() {
+  let test_app_info: AppInfo = AppInfo {
+    version: Some("1.2.3"),
+    ..TEST_APP_INFO_0
+  };
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &test_app_info,
+    arg_options: &[OptionConfig::long("quiet").short('q').help("Quiet")],
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: true,
+  };
+
+  const EXPECTED: &str = "\n\
+    TEST_NAME_0 1.2.3\n\
+    TEST_COPYRIGHT_0\n\
+    TEST_CONTACT_0\n\
+    TEST_ABOUT_0\n\
+    \n\
+    USAGE:\n  \
+      example [-qV] [--verbose]\n\
+    \n\
     OPTIONS:\n  \
-      -0, --TEST_NAME_LONG_0  TEST_BRIEF_DESCRIPTION_0\n  \
-      -1, --TEST_NAME_LONG_1  TEST_BRIEF_DESCRIPTION_1\n";
+      -q, --quiet    Quiet\n  \
+      -V, --version  Show version information\n  \
+      --verbose      Show the build information with --version\n";
 
-  let actual: String = test_help_info.make_print_string();
+  let actual: String = test_help_info.make_print_string_with_width(80);
 
   assert_eq!(actual, EXPECTED);
+
+  let test_help_info_without_version: HelpInfo<'_> = HelpInfo {
+    version_options: false,
+    ..test_help_info
+  };
+
+  assert!(
+    !test_help_info_without_version
+      .make_print_string_with_width(80)
+      .contains("--version")
+  );
+}
+
+#[test]
+fn test_make_usage_string_0() {
+  const TEST_OPTION_CONFIGS: &[OptionConfig] = &[
+    OptionConfig {
+      brief_description: None,
+      display_order: None,
+      heading: None,
+      long_description: None,
+      parse_option_config: ParseOptionConfig {
+        deprecated: false,
+        duplicate_policy: DuplicatePolicy::Accumulate,
+        hidden: false,
+        id: "file",
+        name: ParseOptionName::Long("file"),
+        replacement: None,
+        required: false,
+        value_usage: ValueUsage::Required,
+      },
+      value_name: None,
+    },
+    ARG_OPTION_TEST_1,
+    OptionConfig {
+      brief_description: None,
+      display_order: None,
+      heading: None,
+      long_description: None,
+      parse_option_config: ParseOptionConfig {
+        deprecated: false,
+        duplicate_policy: DuplicatePolicy::Accumulate,
+        hidden: false,
+        id: "url",
+        name: ParseOptionName::Long("url"),
+        replacement: None,
+        required: false,
+        value_usage: ValueUsage::Required,
+      },
+      value_name: None,
+    },
+    OptionConfig {
+      brief_description: None,
+      display_order: None,
+      heading: None,
+      long_description: None,
+      parse_option_config: ParseOptionConfig {
+        deprecated: false,
+        duplicate_policy: DuplicatePolicy::Accumulate,
+        hidden: false,
+        id: "stdin",
+        name: ParseOptionName::Long("stdin"),
+        replacement: None,
+        required: false,
+        value_usage: ValueUsage::Verboten,
+      },
+      value_name: None,
+    },
+  ];
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: TEST_OPTION_CONFIGS,
+    command_name: Some("TEST_COMMAND_NAME_0"),
+    parse_groups: &[
+      ParseGroup {
+        group_usage: GroupUsage::ExactlyOne,
+        id: "source",
+        ids: &[
+          "file", "url", "stdin",
+        ],
+      },
+    ],
+    positionals: &[HelpItem::new("<FILE>...")],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  const EXPECTED: &str = "  TEST_COMMAND_NAME_0 (--file=FILE | --url=URL | --stdin) [-1] <FILE>...\n";
+
+  let actual: String = test_help_info.make_usage_string();
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_usage_string_1() {
+  const TEST_OPTION_CONFIGS: &[OptionConfig] = &[
+    OptionConfig::long("help").short('h'),
+    OptionConfig::long("interactive")
+      .short('i')
+      .optional_value(),
+    OptionConfig::long("name")
+      .short('n')
+      .required_value()
+      .required(),
+    OptionConfig::long("quiet").short('q'),
+  ];
+
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: TEST_OPTION_CONFIGS,
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[HelpItem::new("<FILE>...")],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  const EXPECTED: &str =
+    "  example [-hq] [-i[=INTERACTIVE]] -n=NAME <FILE>...\n";
+
+  let actual: String = test_help_info.make_usage_string();
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_usage_string_2() {
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: &[ARG_OPTION_TEST_0],
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: Some("example [OPTIONS] <FILE>"),
+    version_options: false,
+  };
+
+  const EXPECTED: &str = "  example [OPTIONS] <FILE>\n";
+
+  let actual: String = test_help_info.make_usage_string();
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_write_all_to_0() {
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: &[ARG_OPTION_TEST_0],
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  let mut actual: String = String::new();
+
+  test_help_info.write_all_to(&mut actual).unwrap();
+
+  assert_eq!(actual, test_help_info.make_all_print_string());
+}
+
+#[test]
+fn test_write_for_to_0() {
+  let test_help_info: HelpInfo<'_> = HelpInfo {
+    app_info: &TEST_APP_INFO_0,
+    arg_options: &[ARG_OPTION_TEST_0],
+    command_name: Some("example"),
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  let mut actual_long: String = String::new();
+
+  test_help_info
+    .write_for_to(
+      &ParseFound::Long {
+        arg_index: 1,
+        name_long: "help".to_string(),
+      },
+      &mut actual_long,
+    )
+    .unwrap();
+
+  assert_eq!(actual_long, test_help_info.make_long_print_string());
+
+  let mut actual_short: String = String::new();
+
+  test_help_info
+    .write_for_to(
+      &ParseFound::Short {
+        arg_index: 1,
+        char_index: 0,
+        name_short: 'h',
+      },
+      &mut actual_short,
+    )
+    .unwrap();
+
+  assert_eq!(actual_short, test_help_info.make_print_string());
 }
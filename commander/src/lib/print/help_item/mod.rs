@@ -0,0 +1,101 @@
+//==============================================================================
+//! Module for HelpItem
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::wrap;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// A positional argument or subcommand shown for -\-help
+//------------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HelpItem<'a> {
+  pub brief_description: Option<&'a str>,
+  /// The name such as "<FILE>..." for a positional argument or "build" for a
+  /// subcommand
+  pub name: &'a str,
+}
+
+impl<'a> HelpItem<'a> {
+  //----------------------------------------------------------------------------
+  /// Sets the brief description shown for -\-help
+  //----------------------------------------------------------------------------
+  pub const fn help(
+    mut self,
+    brief_description: &'a str,
+  ) -> Self {
+    self.brief_description = Some(brief_description);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// The item descriptions aligned to a column and wrapped to a fixed width
+  //----------------------------------------------------------------------------
+  pub fn make_print_string_for_slice_with_width(
+    help_items: &[HelpItem],
+    width: usize,
+  ) -> String {
+    let name_len_max: usize = help_items
+      .iter()
+      .map(|help_item: &HelpItem| wrap::display_width(help_item.name))
+      .max()
+      .unwrap_or(0);
+
+    let mut print_string: String = String::new();
+
+    for help_item in help_items {
+      print_string
+        .push_str(&help_item.make_print_string_with_width(name_len_max, width));
+
+      print_string.push('\n');
+    }
+
+    print_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// The item description aligned to a column and wrapped to a fixed width
+  ///
+  /// - The name_len_max is the display width of the widest item name
+  //----------------------------------------------------------------------------
+  pub fn make_print_string_with_width(
+    &self,
+    name_len_max: usize,
+    width: usize,
+  ) -> String {
+    let prefix: String = format!("  {}", self.name);
+
+    let Some(brief_description) = self.brief_description else {
+      return prefix;
+    };
+
+    wrap::make_column_string(
+      &prefix,
+      brief_description,
+      4 + name_len_max,
+      width,
+    )
+  }
+
+  //----------------------------------------------------------------------------
+  /// An item with the name and without a description
+  //----------------------------------------------------------------------------
+  pub const fn new(name: &'a str) -> Self {
+    Self {
+      brief_description: None,
+      name,
+    }
+  }
+}
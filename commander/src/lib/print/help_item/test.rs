@@ -0,0 +1,44 @@
+//==============================================================================
+//! Unit tests for HelpItem
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+#[test]
+fn test_make_print_string_for_slice_with_width_0() {
+  const EXPECTED: &str = "  build      Compile the package\n  \
+    <FILE>...  The files to read\n  \
+    run\n";
+
+  let actual: String = HelpItem::make_print_string_for_slice_with_width(
+    &[
+      HelpItem::new("build").help("Compile the package"),
+      HelpItem::new("<FILE>...").help("The files to read"),
+      HelpItem::new("run"),
+    ],
+    80,
+  );
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_with_width_0() {
+  const EXPECTED: &str = "  build  Compile the package\n         \
+    and its dependencies";
+
+  let actual: String = HelpItem::new("build")
+    .help("Compile the package and its dependencies")
+    .make_print_string_with_width(5, 29);
+
+  assert_eq!(actual, EXPECTED);
+}
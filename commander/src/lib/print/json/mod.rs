@@ -0,0 +1,274 @@
+//==============================================================================
+//! Module for machine-readable JSON output of the parse results
+//!
+//! - Writes the JSON directly without depending on a JSON library
+//! - Each option is an object with the kind, id, name, arg_index, char_index,
+//!   value, and message
+//! - The kind is "option" for a valid known option, "unknown_option" for an
+//!   unknown option, or the kind of parsing error such as "invalid_value"
+//! - Each rule error is an object with the kind, message, and the location
+//!   of the first option involved, if any
+//! - Each warning is an object with the kind, id, name, arg_index,
+//!   char_index, replacement, and message
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
+use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_results::ParseResults;
+use crate::parse::parse_rule_error::ParseRuleError;
+use crate::parse::parse_warning::ParseWarning;
+
+use super::{format_error_option, format_unknown_option};
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Makes a JSON array of the options with parsing errors
+//------------------------------------------------------------------------------
+pub fn format_error_options_json(error_options: &[ParseOutput]) -> String {
+  make_array(error_options.iter().map(format_parse_output_json))
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON object describing a parsed option
+///
+/// - The kind of an unknown option is "unknown_option" even with an error
+//------------------------------------------------------------------------------
+pub fn format_parse_output_json(parse_output: &ParseOutput) -> String {
+  let (kind, message_option): (&str, Option<String>) =
+    if parse_output.known.is_none() {
+      ("unknown_option", Some(format_unknown_option(parse_output)))
+    } else if let Some(parse_error) = &parse_output.error {
+      (
+        make_error_kind(parse_error),
+        Some(format_error_option(parse_output)),
+      )
+    } else {
+      ("option", None)
+    };
+
+  let found: &ParseFound = &parse_output.found;
+
+  format!(
+    "{{\"kind\":{},\"id\":{},\"name\":{},\"arg_index\":{},\
+      \"char_index\":{},\"value\":{},\"message\":{}}}",
+    make_string(kind),
+    make_optional_string(parse_output.known.as_deref()),
+    make_string(&found.get_name()),
+    found.get_arg_index(),
+    make_optional_number(found.get_char_index()),
+    make_optional_string(parse_output.value.as_deref()),
+    make_optional_string(message_option.as_deref()),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON object of all of the parsed options and the rule errors
+///
+/// - The "options" are in the order found
+/// - "has_errors" is true if there are parsing errors, unknown options, or
+///   rule errors
+//------------------------------------------------------------------------------
+pub fn format_parse_results_json(parse_results: &ParseResults) -> String {
+  format!(
+    "{{\"has_errors\":{},\"options\":{},\"rule_errors\":{},\
+      \"warnings\":{}}}",
+    parse_results.has_errors(),
+    make_array(
+      parse_results
+        .parse_outputs
+        .iter()
+        .map(format_parse_output_json)
+    ),
+    make_array(parse_results.rule_errors.iter().map(format_rule_error_json)),
+    make_array(parse_results.warnings.iter().map(format_warning_json)),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON array of the problems in the parse results
+///
+/// - Lists the options with parsing errors, then the unknown options, then
+///   the errors found after the parse, and then the warnings
+/// - Lists an unknown option with an error such as "-\-unknown=" only once,
+///   with the unknown options
+//------------------------------------------------------------------------------
+pub fn format_report_json(parse_results: &ParseResults) -> String {
+  let error_options: Vec<String> = parse_results
+    .parse_outputs
+    .iter()
+    .filter(|parse_output: &&ParseOutput| {
+      parse_output.error.is_some() && parse_output.known.is_some()
+    })
+    .map(format_parse_output_json)
+    .collect();
+
+  let unknown_options: Vec<String> = parse_results
+    .parse_outputs
+    .iter()
+    .filter(|parse_output: &&ParseOutput| parse_output.known.is_none())
+    .map(format_parse_output_json)
+    .collect();
+
+  let rule_errors: Vec<String> = parse_results
+    .rule_errors
+    .iter()
+    .map(format_rule_error_json)
+    .collect();
+
+  let warnings: Vec<String> = parse_results
+    .warnings
+    .iter()
+    .map(format_warning_json)
+    .collect();
+
+  make_array(
+    error_options
+      .into_iter()
+      .chain(unknown_options)
+      .chain(rule_errors)
+      .chain(warnings),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON object describing an error found after a full parse
+//------------------------------------------------------------------------------
+pub fn format_rule_error_json(rule_error: &ParseRuleError) -> String {
+  let (kind, found_option): (&str, Option<&ParseFound>) = match rule_error {
+    ParseRuleError::Conflict {
+      found,
+      ..
+    } => ("conflict", Some(found)),
+    ParseRuleError::GroupMissing {
+      ..
+    } => ("group_missing", None),
+    ParseRuleError::GroupMultiple {
+      founds,
+      ..
+    } => ("group_multiple", founds.first()),
+    ParseRuleError::MissingOptions {
+      ..
+    } => ("missing_options", None),
+    ParseRuleError::RequirementMissing {
+      found,
+      ..
+    } => ("requirement_missing", Some(found)),
+  };
+
+  let (name, arg_index, char_index): (String, String, String) =
+    match found_option {
+      Some(found) => (
+        make_string(&found.get_name()),
+        found.get_arg_index().to_string(),
+        make_optional_number(found.get_char_index()),
+      ),
+      None => ("null".to_string(), "null".to_string(), "null".to_string()),
+    };
+
+  format!(
+    "{{\"kind\":{},\"name\":{},\"arg_index\":{},\"char_index\":{},\
+      \"message\":{}}}",
+    make_string(kind),
+    name,
+    arg_index,
+    char_index,
+    make_string(&rule_error.to_string()),
+  )
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON array of the unknown options
+//------------------------------------------------------------------------------
+pub fn format_unknown_options_json(unknown_options: &[ParseOutput]) -> String {
+  make_array(unknown_options.iter().map(format_parse_output_json))
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON object describing a warning from a full parse
+//------------------------------------------------------------------------------
+pub fn format_warning_json(warning: &ParseWarning) -> String {
+  match warning {
+    ParseWarning::Deprecated {
+      found,
+      id,
+      replacement,
+    } => format!(
+      "{{\"kind\":\"deprecated\",\"id\":{},\"name\":{},\"arg_index\":{},\
+        \"char_index\":{},\"replacement\":{},\"message\":{}}}",
+      make_string(id),
+      make_string(&found.get_name()),
+      found.get_arg_index(),
+      make_optional_number(found.get_char_index()),
+      make_optional_string(replacement.as_deref()),
+      make_string(&warning.to_string()),
+    ),
+  }
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+fn make_array(values: impl Iterator<Item = String>) -> String {
+  let values: Vec<String> = values.collect();
+
+  format!("[{}]", values.join(","))
+}
+
+fn make_error_kind(parse_error: &ParseError) -> &'static str {
+  match parse_error {
+    ParseError::DuplicateOption {
+      ..
+    } => "duplicate_option",
+    ParseError::InvalidValue => "invalid_value",
+    ParseError::RequiredValueMissing => "required_value_missing",
+    ParseError::ValueMissingAfterEquals => "value_missing_after_equals",
+    ParseError::VerbotenValuePresent => "verboten_value_present",
+  }
+}
+
+fn make_optional_number(value_option: Option<usize>) -> String {
+  value_option
+    .map_or_else(|| "null".to_string(), |value: usize| value.to_string())
+}
+
+fn make_optional_string(value_option: Option<&str>) -> String {
+  value_option.map_or_else(|| "null".to_string(), make_string)
+}
+
+//------------------------------------------------------------------------------
+/// Makes a JSON string literal with the special characters escaped
+//------------------------------------------------------------------------------
+fn make_string(value: &str) -> String {
+  let mut json_string: String = String::from("\"");
+
+  for c in value.chars() {
+    match c {
+      '"' => json_string.push_str("\\\""),
+      '\\' => json_string.push_str("\\\\"),
+      '\n' => json_string.push_str("\\n"),
+      '\r' => json_string.push_str("\\r"),
+      '\t' => json_string.push_str("\\t"),
+      c if c.is_control() => {
+        json_string.push_str(&format!("\\u{:04x}", c as u32));
+      },
+      c => json_string.push(c),
+    }
+  }
+
+  json_string.push('"');
+
+  json_string
+}
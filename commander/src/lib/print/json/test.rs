@@ -0,0 +1,127 @@
+//==============================================================================
+//! Unit tests for module json
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+#[test]
+fn test_format_parse_output_json_0() {
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::InvalidValue),
+    found: ParseFound::Short {
+      arg_index: 1,
+      char_index: 0,
+      name_short: 'i',
+    },
+    known: Some("interactive".to_string()),
+    suggestions: Vec::new(),
+    value: Some("in\"valid".to_string()),
+  };
+
+  let expected: &str = "{\"kind\":\"invalid_value\",\"id\":\"interactive\",\
+    \"name\":\"i\",\"arg_index\":1,\"char_index\":0,\
+    \"value\":\"in\\\"valid\",\"message\":\"Invalid value for option at \
+    argument index 1 character index 0: 'i'\"}";
+
+  let actual: String = format_parse_output_json(&test_parse_output);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_parse_results_json_0() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![
+      ParseOutput {
+        error: None,
+        found: ParseFound::Long {
+          arg_index: 1,
+          name_long: "name".to_string(),
+        },
+        known: Some("name".to_string()),
+        suggestions: Vec::new(),
+        value: Some("David".to_string()),
+      },
+    ],
+    rule_errors: vec![
+      ParseRuleError::MissingOptions {
+        ids: vec!["TEST_ID".to_string()],
+        names: vec!["--TEST".to_string()],
+      },
+    ],
+    warnings: Vec::new(),
+  };
+
+  let expected: &str = "{\"has_errors\":true,\"options\":[{\"kind\":\
+    \"option\",\"id\":\"name\",\"name\":\"name\",\"arg_index\":1,\
+    \"char_index\":null,\"value\":\"David\",\"message\":null}],\
+    \"rule_errors\":[{\"kind\":\"missing_options\",\"name\":null,\
+    \"arg_index\":null,\"char_index\":null,\
+    \"message\":\"Missing required options: --TEST\"}],\"warnings\":[]}";
+
+  let actual: String = format_parse_results_json(&test_parse_results);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_unknown_options_json_0() {
+  let test_unknown_options: Vec<ParseOutput> = vec![
+    ParseOutput {
+      error: None,
+      found: ParseFound::Long {
+        arg_index: 1,
+        name_long: "nmae".to_string(),
+      },
+      known: None,
+      suggestions: vec!["--name".to_string()],
+      value: None,
+    },
+  ];
+
+  let expected: &str = "[{\"kind\":\"unknown_option\",\"id\":null,\
+    \"name\":\"nmae\",\"arg_index\":1,\"char_index\":null,\"value\":null,\
+    \"message\":\"Unknown option at argument index 1: \\\"nmae\\\", did you \
+    mean --name?\"}]";
+
+  let actual: String = format_unknown_options_json(&test_unknown_options);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_unknown_options_json_1() {
+  let actual: String = format_unknown_options_json(&[]);
+
+  assert_eq!(actual, "[]");
+}
+
+#[test]
+fn test_format_warning_json_0() {
+  let test_warning: ParseWarning = ParseWarning::Deprecated {
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "out".to_string(),
+    },
+    id: "out".to_string(),
+    replacement: Some("--output".to_string()),
+  };
+
+  let expected: &str = "{\"kind\":\"deprecated\",\"id\":\"out\",\
+    \"name\":\"out\",\"arg_index\":1,\"char_index\":null,\
+    \"replacement\":\"--output\",\"message\":\"Deprecated option at \
+    argument index 1: \\\"out\\\", use --output instead\"}";
+
+  let actual: String = format_warning_json(&test_warning);
+
+  assert_eq!(actual, expected);
+}
@@ -5,57 +5,109 @@
 //! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-04-29
-//! - Updated: 2026-04-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
-use crate::parse::parse_error::ParseError;
-use crate::parse::parse_found::ParseFound;
+use std::fmt;
+use std::io;
+use std::process;
+
+use crate::parse::parse_config_issue::ParseConfigIssue;
+use crate::parse::parse_option_error::ParseOptionError;
 use crate::parse::parse_output::ParseOutput;
+use crate::parse::parse_results::ParseResults;
+use crate::parse::parse_rule_error::ParseRuleError;
+use crate::parse::parse_warning::ParseWarning;
+
+use self::output_format::OutputFormat;
 
 pub mod app_info;
+pub mod build_info;
+pub mod diagnostic;
 pub mod help_info;
+pub mod help_item;
+pub mod json;
 pub mod option_config;
+pub mod output_format;
+pub mod owned_option_config;
+pub mod version_option;
+pub mod wrap;
 
 #[cfg(test)]
 mod test;
 
 //------------------------------------------------------------------------------
-/// Makes a message about an error option.
+/// The sysexits EX_USAGE exit code for a command used incorrectly
 //------------------------------------------------------------------------------
-pub fn format_error_option(error_option: &ParseOutput) -> String {
-  let parse_error: ParseError = error_option.error.unwrap();
-
-  let error_message: &'static str = match parse_error {
-    ParseError::InvalidValue => "Invalid value",
-    ParseError::RequiredValueMissing => "Required value missing",
-    ParseError::ValueMissingAfterEquals => "Value missing after equals",
-    ParseError::VerbotenValuePresent => "Verboten value present",
-  };
+pub const EXIT_CODE_EX_USAGE: i32 = 64;
 
-  let parse_found: &ParseFound = &error_option.found;
+//------------------------------------------------------------------------------
+/// The conventional exit code for a command-line usage error
+//------------------------------------------------------------------------------
+pub const EXIT_CODE_USAGE: i32 = 2;
 
-  match parse_found {
-    ParseFound::Long {
-      arg_index,
+//------------------------------------------------------------------------------
+/// Makes a message about a mistake in the option configurations.
+//------------------------------------------------------------------------------
+pub fn format_config_issue(config_issue: &ParseConfigIssue) -> String {
+  match config_issue {
+    ParseConfigIssue::DigitNameShort {
+      id,
+      name_short,
+    } => format!(
+      "Short name '{name_short}' of option \"{id}\" clashes with negative numbers"
+    ),
+    ParseConfigIssue::DuplicateId {
+      id,
+    } => format!("Duplicate option id \"{id}\""),
+    ParseConfigIssue::DuplicateNameLong {
+      first_id,
+      id,
       name_long,
     } => format!(
-      "{} for option at argument index {}: \"{}\"",
-      error_message, arg_index, name_long,
+      "Long name \"{name_long}\" of option \"{id}\" already used by \"{first_id}\""
     ),
-    ParseFound::Short {
-      arg_index,
-      char_index,
+    ParseConfigIssue::DuplicateNameShort {
+      first_id,
+      id,
       name_short,
     } => format!(
-      "{} for option at argument index {} character index {}: '{}'",
-      error_message, arg_index, char_index, name_short,
+      "Short name '{name_short}' of option \"{id}\" already used by \"{first_id}\""
     ),
+    ParseConfigIssue::EmptyId => "Empty option id".to_string(),
+    ParseConfigIssue::EmptyNameLong {
+      id,
+    } => format!("Empty long name for option \"{id}\""),
+    ParseConfigIssue::HiddenRequired {
+      id,
+    } => format!("Option \"{id}\" is both hidden and required"),
+    ParseConfigIssue::NameContainsEquals {
+      id,
+      name,
+    } => format!("Name \"{name}\" of option \"{id}\" contains \"=\""),
+    ParseConfigIssue::NameStartsWithHyphen {
+      id,
+      name,
+    } => format!("Name \"{name}\" of option \"{id}\" starts with \"-\""),
+    ParseConfigIssue::UnknownId {
+      id,
+    } => format!("Unknown option id \"{id}\" in a relation or group"),
   }
 }
 
+//------------------------------------------------------------------------------
+/// Makes a message about an error option.
+//------------------------------------------------------------------------------
+pub fn format_error_option(error_option: &ParseOutput) -> String {
+  error_option
+    .to_error()
+    .map(|parse_option_error: ParseOptionError| parse_option_error.to_string())
+    .unwrap_or_default()
+}
+
 //------------------------------------------------------------------------------
 /// Makes a message about error options.
 //------------------------------------------------------------------------------
@@ -71,29 +123,104 @@ pub fn format_error_options(error_options: &Vec<ParseOutput>) -> String {
   error_options_string
 }
 
+//------------------------------------------------------------------------------
+/// Makes a report of the problems in the parse results, one per line.
+///
+/// - Lists the options with parsing errors, then the unknown options, then
+///   the errors found after the parse, and then the warnings
+/// - Reports an unknown option with an error such as "-\-unknown=" only once,
+///   as an unknown option
+/// - Returns an empty string if there are no problems
+//------------------------------------------------------------------------------
+pub fn format_report(parse_results: &ParseResults) -> String {
+  let mut report: String = String::new();
+
+  for parse_output in &parse_results.parse_outputs {
+    if parse_output.error.is_some() && parse_output.known.is_some() {
+      report.push_str(&format_error_option(parse_output));
+
+      report.push('\n');
+    }
+  }
+
+  for parse_output in &parse_results.parse_outputs {
+    if parse_output.known.is_none() {
+      report.push_str(&format_unknown_option(parse_output));
+
+      report.push('\n');
+    }
+  }
+
+  for rule_error in &parse_results.rule_errors {
+    report.push_str(&format_rule_error(rule_error));
+
+    report.push('\n');
+  }
+
+  report.push_str(&format_warnings(&parse_results.warnings));
+
+  report
+}
+
+//------------------------------------------------------------------------------
+/// Makes a report of the problems in the parse results in the output format.
+///
+/// - The JSON format is a single line array of problem objects
+/// - Returns an empty string if there are no problems
+//------------------------------------------------------------------------------
+pub fn format_report_as(
+  parse_results: &ParseResults,
+  output_format: OutputFormat,
+) -> String {
+  match output_format {
+    OutputFormat::Json => {
+      if !parse_results.has_errors() && parse_results.warnings.is_empty() {
+        return String::new();
+      }
+
+      format!("{}\n", json::format_report_json(parse_results))
+    },
+    OutputFormat::Text => format_report(parse_results),
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Makes a message about an error found after a full parse.
+//------------------------------------------------------------------------------
+pub fn format_rule_error(rule_error: &ParseRuleError) -> String {
+  rule_error.to_string()
+}
+
+//------------------------------------------------------------------------------
+/// Makes a message about errors found after a full parse.
+//------------------------------------------------------------------------------
+pub fn format_rule_errors(rule_errors: &[ParseRuleError]) -> String {
+  let mut rule_errors_string: String = String::new();
+
+  for rule_error in rule_errors {
+    let rule_error_string: String = format_rule_error(rule_error);
+
+    rule_errors_string.push_str(&rule_error_string);
+  }
+
+  rule_errors_string
+}
+
 //------------------------------------------------------------------------------
 /// Makes a message about an unknown option.
 //------------------------------------------------------------------------------
 pub fn format_unknown_option(unknown_option: &ParseOutput) -> String {
-  let parse_found: &ParseFound = &unknown_option.found;
+  let message: String = format!("Unknown option at {}", unknown_option.found);
 
-  match parse_found {
-    ParseFound::Long {
-      arg_index,
-      name_long,
-    } => format!(
-      "Unknown option at argument index {}: \"{}\"",
-      arg_index, name_long,
-    ),
-    ParseFound::Short {
-      arg_index,
-      char_index,
-      name_short,
-    } => format!(
-      "Unknown option at argument index {} character index {}: '{}'",
-      arg_index, char_index, name_short,
-    ),
+  if unknown_option.suggestions.is_empty() {
+    return message;
   }
+
+  format!(
+    "{}, did you mean {}?",
+    message,
+    unknown_option.suggestions.join(" or "),
+  )
 }
 
 //------------------------------------------------------------------------------
@@ -112,29 +239,282 @@ pub fn format_unknown_options(unknown_options: &Vec<ParseOutput>) -> String {
 }
 
 //------------------------------------------------------------------------------
-/// Prints a message about options with parsing errors.
+/// Makes a message about the warnings from a full parse, one per line.
+///
+/// - Returns an empty string if there are no warnings
+//------------------------------------------------------------------------------
+pub fn format_warnings(warnings: &[ParseWarning]) -> String {
+  let mut warnings_string: String = String::new();
+
+  for warning in warnings {
+    warnings_string.push_str(&format!("warning: {warning}\n"));
+  }
+
+  warnings_string
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about options with parsing errors to standard error.
 //------------------------------------------------------------------------------
 pub fn print_error_options(error_options: &Vec<ParseOutput>) {
   let error_options_string: String = format_error_options(error_options);
 
-  println!("{error_options_string}")
+  eprintln!("{error_options_string}")
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about options with parsing errors to an output stream.
+//------------------------------------------------------------------------------
+pub fn print_error_options_to(
+  error_options: &Vec<ParseOutput>,
+  writer: &mut dyn io::Write,
+) -> io::Result<()> {
+  writeln!(writer, "{}", format_error_options(error_options))
+}
+
+//------------------------------------------------------------------------------
+/// Prints a report of the problems in the parse results to standard error.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn print_report(parse_results: &ParseResults) -> bool {
+  eprint!("{}", format_report(parse_results));
+
+  parse_results.has_errors()
+}
+
+//------------------------------------------------------------------------------
+/// Prints a report in the output format to standard error.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn print_report_as(
+  parse_results: &ParseResults,
+  output_format: OutputFormat,
+) -> bool {
+  eprint!("{}", format_report_as(parse_results, output_format));
+
+  parse_results.has_errors()
 }
 
 //------------------------------------------------------------------------------
-/// Prints a message about an unknown option.
+/// Prints a report in the output format to an output stream.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn print_report_as_to(
+  parse_results: &ParseResults,
+  output_format: OutputFormat,
+  writer: &mut dyn io::Write,
+) -> io::Result<bool> {
+  let report: String = format_report_as(parse_results, output_format);
+
+  writer.write_all(report.as_bytes())?;
+
+  Ok(parse_results.has_errors())
+}
+
+//------------------------------------------------------------------------------
+/// Prints a report of the problems in the parse results to an output stream.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn print_report_to(
+  parse_results: &ParseResults,
+  writer: &mut dyn io::Write,
+) -> io::Result<bool> {
+  let report: String = format_report(parse_results);
+
+  writer.write_all(report.as_bytes())?;
+
+  Ok(parse_results.has_errors())
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about errors found after a full parse to standard error.
+//------------------------------------------------------------------------------
+pub fn print_rule_errors(rule_errors: &[ParseRuleError]) {
+  let rule_errors_string: String = format_rule_errors(rule_errors);
+
+  eprintln!("{rule_errors_string}")
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about errors found after a full parse to an output stream.
+//------------------------------------------------------------------------------
+pub fn print_rule_errors_to(
+  rule_errors: &[ParseRuleError],
+  writer: &mut dyn io::Write,
+) -> io::Result<()> {
+  writeln!(writer, "{}", format_rule_errors(rule_errors))
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about an unknown option to standard error.
 //------------------------------------------------------------------------------
 pub fn print_unknown_option(unknown_option: &ParseOutput) {
   let unknown_option_string: String = format_unknown_option(unknown_option);
 
-  // TODO: Output to standard error?
-  println!("{}", unknown_option_string);
+  eprintln!("{}", unknown_option_string);
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about an unknown option to an output stream.
+//------------------------------------------------------------------------------
+pub fn print_unknown_option_to(
+  unknown_option: &ParseOutput,
+  writer: &mut dyn io::Write,
+) -> io::Result<()> {
+  writeln!(writer, "{}", format_unknown_option(unknown_option))
 }
 
 //------------------------------------------------------------------------------
-/// Prints a message about unknown options.
+/// Prints a message about unknown options to standard error.
 //------------------------------------------------------------------------------
 pub fn print_unknown_options(unknown_options: &Vec<ParseOutput>) {
   let unknown_options_string: String = format_unknown_options(unknown_options);
 
-  println!("{}", unknown_options_string)
+  eprintln!("{}", unknown_options_string)
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about unknown options to an output stream.
+//------------------------------------------------------------------------------
+pub fn print_unknown_options_to(
+  unknown_options: &Vec<ParseOutput>,
+  writer: &mut dyn io::Write,
+) -> io::Result<()> {
+  writeln!(writer, "{}", format_unknown_options(unknown_options))
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about the warnings from a full parse to standard error.
+//------------------------------------------------------------------------------
+pub fn print_warnings(warnings: &[ParseWarning]) {
+  print_warnings_to(warnings, &mut io::stderr())
+    .expect("failed printing to stderr");
+}
+
+//------------------------------------------------------------------------------
+/// Prints a message about the warnings from a full parse to an output stream.
+//------------------------------------------------------------------------------
+pub fn print_warnings_to(
+  warnings: &[ParseWarning],
+  writer: &mut dyn io::Write,
+) -> io::Result<()> {
+  write!(writer, "{}", format_warnings(warnings))
+}
+
+//------------------------------------------------------------------------------
+/// Prints a report to standard error and exits if there are any errors.
+///
+/// - Leaves standard output clean for piping
+/// - Use EXIT_CODE_USAGE (2) or EXIT_CODE_EX_USAGE (64) as the exit code
+/// - Returns after printing any warnings if there are no errors
+//------------------------------------------------------------------------------
+pub fn report_and_exit(
+  parse_results: &ParseResults,
+  exit_code: i32,
+) {
+  if print_report(parse_results) {
+    process::exit(exit_code);
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Prints a report in the output format to standard error and exits if there
+/// are any errors.
+//------------------------------------------------------------------------------
+pub fn report_and_exit_as(
+  parse_results: &ParseResults,
+  output_format: OutputFormat,
+  exit_code: i32,
+) {
+  if print_report_as(parse_results, output_format) {
+    process::exit(exit_code);
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Writes a message about options with parsing errors to a string or formatter.
+//------------------------------------------------------------------------------
+pub fn write_error_options_to(
+  error_options: &Vec<ParseOutput>,
+  writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+  writeln!(writer, "{}", format_error_options(error_options))
+}
+
+//------------------------------------------------------------------------------
+/// Writes a message about errors found after a full parse to a string or formatter.
+//------------------------------------------------------------------------------
+pub fn write_rule_errors_to(
+  rule_errors: &[ParseRuleError],
+  writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+  writeln!(writer, "{}", format_rule_errors(rule_errors))
+}
+
+//------------------------------------------------------------------------------
+/// Writes a report in the output format to a string or formatter.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn write_report_as_to(
+  parse_results: &ParseResults,
+  output_format: OutputFormat,
+  writer: &mut dyn fmt::Write,
+) -> Result<bool, fmt::Error> {
+  let report: String = format_report_as(parse_results, output_format);
+
+  writer.write_str(&report)?;
+
+  Ok(parse_results.has_errors())
+}
+
+//------------------------------------------------------------------------------
+/// Writes a report of the problems in the parse results to a string.
+///
+/// - Returns true if there were any errors, not counting the warnings
+//------------------------------------------------------------------------------
+pub fn write_report_to(
+  parse_results: &ParseResults,
+  writer: &mut dyn fmt::Write,
+) -> Result<bool, fmt::Error> {
+  let report: String = format_report(parse_results);
+
+  writer.write_str(&report)?;
+
+  Ok(parse_results.has_errors())
+}
+
+//------------------------------------------------------------------------------
+/// Writes a message about an unknown option to a string or formatter.
+//------------------------------------------------------------------------------
+pub fn write_unknown_option_to(
+  unknown_option: &ParseOutput,
+  writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+  writeln!(writer, "{}", format_unknown_option(unknown_option))
+}
+
+//------------------------------------------------------------------------------
+/// Writes a message about unknown options to a string or formatter.
+//------------------------------------------------------------------------------
+pub fn write_unknown_options_to(
+  unknown_options: &Vec<ParseOutput>,
+  writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+  writeln!(writer, "{}", format_unknown_options(unknown_options))
+}
+
+//------------------------------------------------------------------------------
+/// Writes a message about the warnings from a full parse to a string or
+/// formatter.
+//------------------------------------------------------------------------------
+pub fn write_warnings_to(
+  warnings: &[ParseWarning],
+  writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+  writer.write_str(&format_warnings(warnings))
 }
@@ -5,18 +5,31 @@
 //! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-06-05
-//! - Updated: 2026-04-16
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use std::fmt;
+use std::io;
+
+use crate::parse::duplicate_policy::DuplicatePolicy;
 use crate::parse::parse_option_config::ParseOptionConfig;
+use crate::parse::parse_option_error::ParseOptionError;
 use crate::parse::parse_output::ParseOutput;
+use crate::parse::value_usage::ValueUsage;
+
+use super::wrap;
 
 #[cfg(test)]
 mod test;
 
+//------------------------------------------------------------------------------
+/// Shorthand for building an option: `Opt::long("name").short('n')`
+//------------------------------------------------------------------------------
+pub type Opt<'a> = OptionConfig<'a>;
+
 //------------------------------------------------------------------------------
 /// Option configuration metadata for parsing and printing
 //------------------------------------------------------------------------------
@@ -24,12 +37,228 @@ mod test;
 pub struct OptionConfig<'a> {
   // TODO: Maybe move this to a PrintConfig
   pub brief_description: Option<&'a str>,
+  /// Options with a display order are shown first for -\-help, lowest first
+  pub display_order: Option<usize>,
+  /// The heading of the section for -\-help, otherwise shown under OPTIONS
+  pub heading: Option<&'a str>,
+  /// The detailed description shown for -\-help after the brief description
+  pub long_description: Option<&'a str>,
   pub parse_option_config: ParseOptionConfig<'a>,
+  /// The placeholder for the value shown for -\-help, such as NAME or BOOL
+  pub value_name: Option<&'a str>,
 }
 
 impl<'a> OptionConfig<'a> {
+  //----------------------------------------------------------------------------
+  /// Panics if any option is invalid or if any two options are not distinct
+  ///
+  /// - Use with a const item to check an option table when building:
+  ///   `const _: () = OptionConfig::assert_valid_slice(&OPTION_CONFIGS);`
+  /// - See ParseOptionConfig::assert_valid_slice for the checks
+  //----------------------------------------------------------------------------
+  pub const fn assert_valid_slice(arg_options: &[OptionConfig]) {
+    let mut index: usize = 0;
+
+    while index < arg_options.len() {
+      let parse_option_config: &ParseOptionConfig =
+        &arg_options[index].parse_option_config;
+
+      parse_option_config.assert_valid();
+
+      let mut other_index: usize = index + 1;
+
+      while other_index < arg_options.len() {
+        parse_option_config
+          .assert_distinct(&arg_options[other_index].parse_option_config);
+
+        other_index += 1;
+      }
+
+      index += 1;
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be deprecated so that a full parse warns if present
+  //----------------------------------------------------------------------------
+  pub const fn deprecated(mut self) -> Self {
+    self.parse_option_config = self.parse_option_config.deprecated();
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the position of the option within its section for -\-help
+  ///
+  /// - Options without a display order follow in declaration order
+  //----------------------------------------------------------------------------
+  pub const fn display_order(
+    mut self,
+    display_order: usize,
+  ) -> Self {
+    self.display_order = Some(display_order);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets what a full parse does when the option occurs more than once
+  //----------------------------------------------------------------------------
+  pub const fn duplicate_policy(
+    mut self,
+    duplicate_policy: DuplicatePolicy,
+  ) -> Self {
+    self.parse_option_config =
+      self.parse_option_config.duplicate_policy(duplicate_policy);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the heading of the section the option is shown under for -\-help
+  //----------------------------------------------------------------------------
+  pub const fn heading(
+    mut self,
+    heading: &'a str,
+  ) -> Self {
+    self.heading = Some(heading);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the brief description shown for -\-help
+  //----------------------------------------------------------------------------
+  pub const fn help(
+    mut self,
+    brief_description: &'a str,
+  ) -> Self {
+    self.brief_description = Some(brief_description);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be accepted but left out of -\-help
+  ///
+  /// - Hidden options are shown by HelpInfo::make_all_print_string
+  //----------------------------------------------------------------------------
+  pub const fn hidden(mut self) -> Self {
+    self.parse_option_config = self.parse_option_config.hidden();
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the id which otherwise defaults to the long name
+  //----------------------------------------------------------------------------
+  pub const fn id(
+    mut self,
+    id: &'a str,
+  ) -> Self {
+    self.parse_option_config = self.parse_option_config.id(id);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// An option with a long name which is also used as the id
+  ///
+  /// - See ParseOptionConfig::new for the defaults
+  //----------------------------------------------------------------------------
+  pub const fn long(name_long: &'a str) -> Self {
+    Self::new(ParseOptionConfig::long(name_long))
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the detailed description shown for -\-help but not for -h
+  ///
+  /// - Paragraphs are separated by a blank line
+  //----------------------------------------------------------------------------
+  pub const fn long_help(
+    mut self,
+    long_description: &'a str,
+  ) -> Self {
+    self.long_description = Some(long_description);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// The detailed option descriptions wrapped to a fixed width
+  ///
+  /// - The options are separated by a blank line
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string_for_slice_with_width(
+    arg_options: &[OptionConfig],
+    width: usize,
+  ) -> String {
+    let option_strings: Vec<String> = arg_options
+      .iter()
+      .map(|arg_option: &OptionConfig| {
+        arg_option.make_long_print_string_with_width(width)
+      })
+      .collect();
+
+    let mut print_string: String = option_strings.join("\n\n");
+
+    if !print_string.is_empty() {
+      print_string.push('\n');
+    }
+
+    print_string
+  }
+
+  //----------------------------------------------------------------------------
+  /// The option prefix followed by the brief and detailed descriptions
+  ///
+  /// - Each paragraph is wrapped on the lines after the option prefix,
+  ///   indented by wrap::NEXT_LINE_INDENT
+  /// - The paragraphs are separated by a blank line
+  //----------------------------------------------------------------------------
+  pub fn make_long_print_string_with_width(
+    &self,
+    width: usize,
+  ) -> String {
+    let indent: String = " ".repeat(wrap::NEXT_LINE_INDENT);
+
+    let paragraph_width: usize =
+      width.saturating_sub(wrap::NEXT_LINE_INDENT).max(1);
+
+    let paragraphs: Vec<String> = self
+      .brief_description
+      .into_iter()
+      .chain(
+        self
+          .long_description
+          .into_iter()
+          .flat_map(|long_description: &str| long_description.split("\n\n")),
+      )
+      .filter(|paragraph: &&str| !paragraph.trim().is_empty())
+      .map(|paragraph: &str| {
+        wrap::wrap_text(paragraph, paragraph_width)
+          .iter()
+          .map(|line: &String| format!("{indent}{line}"))
+          .collect::<Vec<String>>()
+          .join("\n")
+      })
+      .collect();
+
+    let prefix: String = self.make_print_option_prefix();
+
+    if paragraphs.is_empty() {
+      return prefix;
+    }
+
+    format!("{}\n{}", prefix, paragraphs.join("\n\n"))
+  }
+
   //------------------------------------------------------------------------------
   /// String prefix for a command-line option shown for -\-help
+  ///
+  /// - Followed by "=<NAME>" if the value is required
+  /// - Followed by "[=<NAME>]" if the value is optional
+  /// - See make_usage_value for the value placeholder
   //------------------------------------------------------------------------------
   pub fn make_print_option_prefix(&self) -> String {
     let mut prefix: String = "".to_string();
@@ -56,9 +285,22 @@ impl<'a> OptionConfig<'a> {
       prefix.push_str(name_long.unwrap());
     }
 
+    let placeholder: String = self.make_value_placeholder();
+
+    match parse_option_config.value_usage {
+      ValueUsage::Optional => prefix.push_str(&format!("[=<{placeholder}>]")),
+      ValueUsage::Required => prefix.push_str(&format!("=<{placeholder}>")),
+      ValueUsage::Verboten => (),
+    }
+
     prefix
   }
 
+  //----------------------------------------------------------------------------
+  /// The option description aligned to a column after the option prefix
+  ///
+  /// - The prefix_len_max is the display width of the widest option prefix
+  //----------------------------------------------------------------------------
   pub fn make_print_string(
     &self,
     prefix_len_max: usize,
@@ -69,7 +311,8 @@ impl<'a> OptionConfig<'a> {
 
     line.push_str(&prefix);
 
-    let spaces_count: usize = 2 + prefix_len_max - prefix.len();
+    let spaces_count: usize =
+      (2 + prefix_len_max).saturating_sub(wrap::display_width(&prefix));
 
     for _ in 0..spaces_count {
       line.push(' ');
@@ -82,14 +325,34 @@ impl<'a> OptionConfig<'a> {
     line
   }
 
+  //----------------------------------------------------------------------------
+  /// The option descriptions wrapped to the terminal width
+  ///
+  /// - Reads COLUMNS or queries the terminal, see wrap::get_terminal_width
+  /// - Use make_print_string_for_slice_with_width for output that must not
+  ///   vary
+  //----------------------------------------------------------------------------
   pub fn make_print_string_for_slice(arg_options: &[OptionConfig]) -> String {
+    Self::make_print_string_for_slice_with_width(
+      arg_options,
+      wrap::get_terminal_width(),
+    )
+  }
+
+  //----------------------------------------------------------------------------
+  /// The option descriptions wrapped to a fixed width
+  //----------------------------------------------------------------------------
+  pub fn make_print_string_for_slice_with_width(
+    arg_options: &[OptionConfig],
+    width: usize,
+  ) -> String {
     let mut prefix_len_max: usize = 0;
 
     for arg_option in arg_options {
       // TODO: save generated prefix
       let prefix: String = arg_option.make_print_option_prefix();
 
-      let prefix_len: usize = prefix.len();
+      let prefix_len: usize = wrap::display_width(&prefix);
 
       if prefix_len > prefix_len_max {
         prefix_len_max = prefix_len;
@@ -99,7 +362,9 @@ impl<'a> OptionConfig<'a> {
     let mut print_string: String = String::new();
 
     for arg_option in arg_options {
-      print_string.push_str(&arg_option.make_print_string(prefix_len_max));
+      print_string.push_str(
+        &arg_option.make_print_string_with_width(prefix_len_max, width),
+      );
 
       print_string.push('\n');
     }
@@ -107,6 +372,108 @@ impl<'a> OptionConfig<'a> {
     print_string
   }
 
+  //----------------------------------------------------------------------------
+  /// The option description wrapped to a fixed width
+  ///
+  /// - Continuation lines are indented to the description column
+  /// - The description starts on the next line if the description column
+  ///   would leave less than wrap::MIN_DESCRIPTION_WIDTH for the description
+  //----------------------------------------------------------------------------
+  pub fn make_print_string_with_width(
+    &self,
+    prefix_len_max: usize,
+    width: usize,
+  ) -> String {
+    let Some(brief_description) = self.brief_description else {
+      return self.make_print_string(prefix_len_max);
+    };
+
+    wrap::make_column_string(
+      &self.make_print_option_prefix(),
+      brief_description,
+      2 + prefix_len_max,
+      width,
+    )
+  }
+
+  //----------------------------------------------------------------------------
+  /// The option as shown in the usage line, in brackets if not required
+  //----------------------------------------------------------------------------
+  pub fn make_usage_item(&self) -> String {
+    let usage_name: String =
+      format!("{}{}", self.make_usage_name(), self.make_usage_value());
+
+    if self.parse_option_config.required {
+      usage_name
+    } else {
+      format!("[{usage_name}]")
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// The short name if available, otherwise the long name, with hyphens
+  //----------------------------------------------------------------------------
+  pub fn make_usage_name(&self) -> String {
+    let parse_option_config: &ParseOptionConfig = &self.parse_option_config;
+
+    match parse_option_config.name.get_name_short() {
+      Some(name_short) => format!("-{name_short}"),
+      None => parse_option_config.name.make_hyphenated_name(),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// The value placeholder shown after the name in the usage line
+  ///
+  /// - "=NAME" if the value is required
+  /// - "[=NAME]" if the value is optional
+  /// - Empty if the value is verboten
+  /// - The placeholder is the value name if set, otherwise the long name in
+  ///   uppercase, otherwise VALUE
+  //----------------------------------------------------------------------------
+  pub fn make_usage_value(&self) -> String {
+    let placeholder: String = self.make_value_placeholder();
+
+    match self.parse_option_config.value_usage {
+      ValueUsage::Optional => format!("[={placeholder}]"),
+      ValueUsage::Required => format!("={placeholder}"),
+      ValueUsage::Verboten => String::new(),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// The options as shown in the usage line, separated by spaces
+  //----------------------------------------------------------------------------
+  pub fn make_usage_string_for_slice(arg_options: &[OptionConfig]) -> String {
+    let usage_items: Vec<String> = arg_options
+      .iter()
+      .map(|arg_option: &OptionConfig| arg_option.make_usage_item())
+      .collect();
+
+    usage_items.join(" ")
+  }
+
+  //----------------------------------------------------------------------------
+  /// An option with the parse configuration and without a description
+  //----------------------------------------------------------------------------
+  pub const fn new(parse_option_config: ParseOptionConfig<'a>) -> Self {
+    Self {
+      brief_description: None,
+      display_order: None,
+      heading: None,
+      long_description: None,
+      parse_option_config,
+      value_name: None,
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to take an optional value
+  //----------------------------------------------------------------------------
+  pub const fn optional_value(self) -> Self {
+    self.value_usage(ValueUsage::Optional)
+  }
+
   pub fn parse(
     &self,
     args: &'a [String],
@@ -131,10 +498,152 @@ impl<'a> OptionConfig<'a> {
     println!("{}", self.make_print_string(prefix_len_max));
   }
 
+  //----------------------------------------------------------------------------
+  /// Prints a single option description to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_option_to(
+    &self,
+    prefix_len_max: usize,
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writeln!(writer, "{}", self.make_print_string(prefix_len_max))
+  }
+
   //----------------------------------------------------------------------------
   /// Prints multiple option descriptions
   //----------------------------------------------------------------------------
   pub fn print_options(arg_options: &[OptionConfig]) {
     print!("{}", Self::make_print_string_for_slice(arg_options));
   }
+
+  //----------------------------------------------------------------------------
+  /// Prints multiple option descriptions to an output stream
+  //----------------------------------------------------------------------------
+  pub fn print_options_to(
+    arg_options: &[OptionConfig],
+    writer: &mut dyn io::Write,
+  ) -> io::Result<()> {
+    writer.write_all(Self::make_print_string_for_slice(arg_options).as_bytes())
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be deprecated in favor of the replacement option
+  ///
+  /// - The replacement is named in the warning, such as "--output"
+  //----------------------------------------------------------------------------
+  pub const fn replaced_by(
+    mut self,
+    replacement: &'a str,
+  ) -> Self {
+    self.parse_option_config =
+      self.parse_option_config.replaced_by(replacement);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to be required in the command-line arguments
+  //----------------------------------------------------------------------------
+  pub const fn required(mut self) -> Self {
+    self.parse_option_config = self.parse_option_config.required();
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the option to take a required value
+  //----------------------------------------------------------------------------
+  pub const fn required_value(self) -> Self {
+    self.value_usage(ValueUsage::Required)
+  }
+
+  //----------------------------------------------------------------------------
+  /// Adds a short name to the long name or replaces the short name
+  //----------------------------------------------------------------------------
+  pub const fn short(
+    mut self,
+    name_short: char,
+  ) -> Self {
+    self.parse_option_config = self.parse_option_config.short(name_short);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// The error with its context if the error is Some
+  ///
+  /// - The expected form uses the value name if set, otherwise "VALUE"
+  //----------------------------------------------------------------------------
+  pub fn to_error(
+    &self,
+    parse_output: &ParseOutput,
+  ) -> Option<ParseOptionError> {
+    let parse_option_error: ParseOptionError = parse_output.to_error()?;
+
+    match self.value_name {
+      Some(value_name) => Some(parse_option_error.with_value_name(value_name)),
+      None => Some(parse_option_error),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets whether the option takes a value
+  //----------------------------------------------------------------------------
+  pub const fn value_usage(
+    mut self,
+    value_usage: ValueUsage,
+  ) -> Self {
+    self.parse_option_config =
+      self.parse_option_config.value_usage(value_usage);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Sets the placeholder for the value shown for -\-help
+  //----------------------------------------------------------------------------
+  pub const fn value_name(
+    mut self,
+    value_name: &'a str,
+  ) -> Self {
+    self.value_name = Some(value_name);
+
+    self
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes a single option description to a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_option_to(
+    &self,
+    prefix_len_max: usize,
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writeln!(writer, "{}", self.make_print_string(prefix_len_max))
+  }
+
+  //----------------------------------------------------------------------------
+  /// Writes multiple option descriptions to a string or formatter
+  //----------------------------------------------------------------------------
+  pub fn write_options_to(
+    arg_options: &[OptionConfig],
+    writer: &mut dyn fmt::Write,
+  ) -> fmt::Result {
+    writer.write_str(&Self::make_print_string_for_slice(arg_options))
+  }
+
+  // ---------------------------------------------------------------------------
+  // private functions
+  // ---------------------------------------------------------------------------
+
+  fn make_value_placeholder(&self) -> String {
+    if let Some(value_name) = self.value_name {
+      return value_name.to_string();
+    }
+
+    match self.parse_option_config.name.get_name_long() {
+      Some(name_long) => name_long.to_uppercase().replace('-', "_"),
+      None => "VALUE".to_string(),
+    }
+  }
 }
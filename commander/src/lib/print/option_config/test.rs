@@ -2,15 +2,17 @@
 //! Unit tests module Print module
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2025 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-04-02
-//! - Updated: 2025-11-21
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::parse_error::ParseError;
 use crate::parse::parse_found::ParseFound;
 use crate::parse::parse_option_name::ParseOptionName;
 use crate::parse::value_usage::ValueUsage;
@@ -25,40 +27,152 @@ const TEST_NAME_LONG_1: &str = "TEST_NAME_LONG_ABC_1";
 
 const TEST_OPTION_CONFIG_0: OptionConfig = OptionConfig {
   brief_description: Some("TEST_BRIEF_DESCRIPTION_0"),
+  display_order: None,
+  heading: None,
+  long_description: None,
   parse_option_config: ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
     id: TEST_ID_0,
     name: ParseOptionName::Both {
       name_long: TEST_NAME_LONG_0,
       name_short: '0',
     },
+    replacement: None,
+    required: false,
     value_usage: ValueUsage::Required,
   },
+  value_name: Some("NAME"),
 };
 
 const TEST_OPTION_CONFIG_1: OptionConfig = OptionConfig {
   brief_description: Some("TEST_BRIEF_DESCRIPTION_1"),
+  display_order: None,
+  heading: None,
+  long_description: None,
   parse_option_config: ParseOptionConfig {
+    deprecated: false,
+    duplicate_policy: DuplicatePolicy::Accumulate,
+    hidden: false,
     id: TEST_ID_1,
     name: ParseOptionName::Both {
       name_long: TEST_NAME_LONG_1,
       name_short: '1',
     },
+    replacement: None,
+    required: false,
     value_usage: ValueUsage::Verboten,
   },
+  value_name: None,
 };
 
+const _: () = OptionConfig::assert_valid_slice(&[
+  TEST_OPTION_CONFIG_0,
+  TEST_OPTION_CONFIG_1,
+]);
+
+#[test]
+#[should_panic(expected = "Duplicate option id")]
+fn test_assert_valid_slice_0() {
+  OptionConfig::assert_valid_slice(&[
+    TEST_OPTION_CONFIG_0,
+    TEST_OPTION_CONFIG_0,
+  ]);
+}
+
+#[test]
+fn test_long_0() {
+  let actual: OptionConfig = Opt::long(TEST_NAME_LONG_1)
+    .id(TEST_ID_1)
+    .short('1')
+    .duplicate_policy(DuplicatePolicy::Accumulate)
+    .help("TEST_BRIEF_DESCRIPTION_1");
+
+  assert_eq!(
+    actual.brief_description,
+    TEST_OPTION_CONFIG_1.brief_description
+  );
+
+  assert_eq!(
+    actual.parse_option_config,
+    TEST_OPTION_CONFIG_1.parse_option_config
+  );
+}
+
+#[test]
+fn test_make_long_print_string_for_slice_with_width_0() {
+  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0=<NAME>\n        \
+    TEST_BRIEF_DESCRIPTION_0\n\n  \
+    -1, --TEST_NAME_LONG_ABC_1\n        \
+    TEST_BRIEF_DESCRIPTION_1\n";
+
+  let actual: String =
+    OptionConfig::make_long_print_string_for_slice_with_width(
+      &[
+        TEST_OPTION_CONFIG_0,
+        TEST_OPTION_CONFIG_1,
+      ],
+      80,
+    );
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_long_print_string_with_width_0() {
+  const EXPECTED: &str = "  -q, --quiet\n        \
+    Brief text\n\n        \
+    The first long\n        \
+    paragraph\n\n        \
+    Second";
+
+  let test_option_config: OptionConfig = OptionConfig::long("quiet")
+    .short('q')
+    .help("Brief text")
+    .long_help("The first long paragraph\n\nSecond");
+
+  let actual: String = test_option_config.make_long_print_string_with_width(24);
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_long_print_string_with_width_1() {
+  let test_option_config: OptionConfig = OptionConfig::long("quiet");
+
+  let actual: String = test_option_config.make_long_print_string_with_width(80);
+
+  assert_eq!(actual, "  --quiet");
+}
+
 #[test]
 fn test_make_print_option_prefix_0() {
-  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0";
+  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0=<NAME>";
 
   let actual: String = TEST_OPTION_CONFIG_0.make_print_option_prefix();
 
   assert_eq!(actual, EXPECTED);
 }
 
+#[test]
+fn test_make_print_option_prefix_1() {
+  const EXPECTED: &str = "  -i, --interactive[=<BOOL>]";
+
+  let test_option_config: OptionConfig = OptionConfig::long("interactive")
+    .short('i')
+    .optional_value()
+    .value_name("BOOL");
+
+  let actual: String = test_option_config.make_print_option_prefix();
+
+  assert_eq!(actual, EXPECTED);
+}
+
 #[test]
 fn test_make_print_string_0() {
-  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0  TEST_BRIEF_DESCRIPTION_0";
+  const EXPECTED: &str =
+    "  -0, --TEST_NAME_LONG_0=<NAME>  TEST_BRIEF_DESCRIPTION_0";
 
   let prefix_len: usize = TEST_OPTION_CONFIG_0.make_print_option_prefix().len();
 
@@ -69,10 +183,61 @@ fn test_make_print_string_0() {
 
 #[test]
 fn test_make_print_string_for_slice_0() {
-  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0      TEST_BRIEF_DESCRIPTION_0\n  \
-       -1, --TEST_NAME_LONG_ABC_1  TEST_BRIEF_DESCRIPTION_1\n";
+  const EXPECTED: &str = "  -0, --TEST_NAME_LONG_0=<NAME>  TEST_BRIEF_DESCRIPTION_0\n  \
+       -1, --TEST_NAME_LONG_ABC_1     TEST_BRIEF_DESCRIPTION_1\n";
+
+  let actual: String = OptionConfig::make_print_string_for_slice_with_width(
+    &[
+      TEST_OPTION_CONFIG_0,
+      TEST_OPTION_CONFIG_1,
+    ],
+    80,
+  );
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_usage_item_0() {
+  const EXPECTED: &str = "[-0=NAME]";
+
+  let actual: String = TEST_OPTION_CONFIG_0.make_usage_item();
 
-  let actual: String = OptionConfig::make_print_string_for_slice(&[
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_usage_item_1() {
+  const EXPECTED: &str = "--TEST_NAME_LONG_0=TEST_NAME_LONG_0";
+
+  let test_option_config: OptionConfig = OptionConfig {
+    brief_description: None,
+    display_order: None,
+    heading: None,
+    long_description: None,
+    parse_option_config: ParseOptionConfig {
+      deprecated: false,
+      duplicate_policy: DuplicatePolicy::Accumulate,
+      hidden: false,
+      id: TEST_ID_0,
+      name: ParseOptionName::Long(TEST_NAME_LONG_0),
+      replacement: None,
+      required: true,
+      value_usage: ValueUsage::Required,
+    },
+    value_name: None,
+  };
+
+  let actual: String = test_option_config.make_usage_item();
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_usage_string_for_slice_0() {
+  const EXPECTED: &str = "[-0=NAME] [-1]";
+
+  let actual: String = OptionConfig::make_usage_string_for_slice(&[
     TEST_OPTION_CONFIG_0,
     TEST_OPTION_CONFIG_1,
   ]);
@@ -96,6 +261,7 @@ fn test_parse_0() {
         name_short: '0',
       },
       known: Some("TEST_ID_0".to_string()),
+      suggestions: Vec::new(),
       value: Some("A".to_string()),
     },
     ParseOutput {
@@ -106,6 +272,7 @@ fn test_parse_0() {
         name_short: '0',
       },
       known: Some("TEST_ID_0".to_string()),
+      suggestions: Vec::new(),
       value: Some("B".to_string()),
     },
   ];
@@ -130,6 +297,7 @@ fn test_parse_last_0() {
       name_short: '0',
     },
     known: Some("TEST_ID_0".to_string()),
+    suggestions: Vec::new(),
     value: Some("B".to_string()),
   });
 
@@ -137,3 +305,130 @@ fn test_parse_last_0() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_print_options_to_0() {
+  let test_option_configs: [OptionConfig; 2] = [
+    TEST_OPTION_CONFIG_0,
+    TEST_OPTION_CONFIG_1,
+  ];
+
+  let mut buffer: Vec<u8> = Vec::new();
+
+  OptionConfig::print_options_to(&test_option_configs, &mut buffer).unwrap();
+
+  let actual: String = String::from_utf8(buffer).unwrap();
+
+  let expected: String =
+    OptionConfig::make_print_string_for_slice(&test_option_configs);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_to_error_0() {
+  let test_option_config: OptionConfig =
+    Opt::long("file").required_value().value_name("FILE");
+
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::RequiredValueMissing),
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "file".to_string(),
+    },
+    known: Some("file".to_string()),
+    suggestions: Vec::new(),
+    value: None,
+  };
+
+  let actual: Option<String> = test_option_config
+    .to_error(&test_parse_output)
+    .map(|parse_option_error: ParseOptionError| parse_option_error.expected);
+
+  assert_eq!(actual.as_deref(), Some("--file=<FILE>"));
+}
+
+#[test]
+fn test_to_error_1() {
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "file".to_string(),
+    },
+    known: Some("file".to_string()),
+    suggestions: Vec::new(),
+    value: Some("a.txt".to_string()),
+  };
+
+  assert_eq!(Opt::long("file").to_error(&test_parse_output), None);
+}
+
+#[test]
+fn test_write_option_to_0() {
+  const EXPECTED: &str =
+    "  -0, --TEST_NAME_LONG_0=<NAME>  TEST_BRIEF_DESCRIPTION_0\n";
+
+  let prefix_len: usize = TEST_OPTION_CONFIG_0.make_print_option_prefix().len();
+
+  let mut actual: String = String::new();
+
+  TEST_OPTION_CONFIG_0
+    .write_option_to(prefix_len, &mut actual)
+    .unwrap();
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_with_width_0() {
+  const EXPECTED: &str = "  -n, --name  one two three four five\n              \
+    six seven eight nine ten";
+
+  let test_option_config: OptionConfig = OptionConfig::long("name")
+    .short('n')
+    .help("one two three four five six seven eight nine ten");
+
+  let prefix_len: usize = test_option_config.make_print_option_prefix().len();
+
+  let actual: String =
+    test_option_config.make_print_string_with_width(prefix_len, 40);
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_with_width_1() {
+  const EXPECTED: &str = "  -n, --name\n        one two three four\n        \
+    five six seven eight\n        nine ten";
+
+  let test_option_config: OptionConfig = OptionConfig::long("name")
+    .short('n')
+    .help("one two three four five six seven eight nine ten");
+
+  let prefix_len: usize = test_option_config.make_print_option_prefix().len();
+
+  let actual: String =
+    test_option_config.make_print_string_with_width(prefix_len, 30);
+
+  assert_eq!(actual, EXPECTED);
+}
+
+#[test]
+fn test_make_print_string_for_slice_with_width_0() {
+  // The wide characters each use two columns and the combining accent none
+  const EXPECTED: &str = "  -n, --名前   Name\n  \
+    -c, --cafe\u{301}   Coffee\n  \
+    -q, --quiet  Quiet\n";
+
+  let actual: String = OptionConfig::make_print_string_for_slice_with_width(
+    &[
+      OptionConfig::long("名前").short('n').help("Name"),
+      OptionConfig::long("cafe\u{301}").short('c').help("Coffee"),
+      OptionConfig::long("quiet").short('q').help("Quiet"),
+    ],
+    80,
+  );
+
+  assert_eq!(actual, EXPECTED);
+}
@@ -0,0 +1,85 @@
+//==============================================================================
+//! Module for OwnedOptionConfig
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::owned_parse_option_config::OwnedParseOptionConfig;
+
+use super::option_config::OptionConfig;
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// Option configuration metadata for parsing and printing which owns its
+/// strings
+///
+/// - For options loaded at runtime such as from plugins or a spec file
+/// - Borrow an OptionConfig view using as_option_config
+//------------------------------------------------------------------------------
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedOptionConfig {
+  pub brief_description: Option<String>,
+  pub display_order: Option<usize>,
+  pub heading: Option<String>,
+  pub long_description: Option<String>,
+  pub parse_option_config: OwnedParseOptionConfig,
+  pub value_name: Option<String>,
+}
+
+impl OwnedOptionConfig {
+  //----------------------------------------------------------------------------
+  /// A borrowed view for use with HelpInfo and the print functions
+  //----------------------------------------------------------------------------
+  pub fn as_option_config(&self) -> OptionConfig<'_> {
+    OptionConfig {
+      brief_description: self.brief_description.as_deref(),
+      display_order: self.display_order,
+      heading: self.heading.as_deref(),
+      long_description: self.long_description.as_deref(),
+      parse_option_config: self.parse_option_config.as_parse_option_config(),
+      value_name: self.value_name.as_deref(),
+    }
+  }
+
+  //----------------------------------------------------------------------------
+  /// Borrowed views of the options, such as for HelpInfo arg_options
+  //----------------------------------------------------------------------------
+  pub fn as_option_configs(
+    owned_option_configs: &[OwnedOptionConfig]
+  ) -> Vec<OptionConfig<'_>> {
+    owned_option_configs
+      .iter()
+      .map(OwnedOptionConfig::as_option_config)
+      .collect()
+  }
+}
+
+impl From<OptionConfig<'_>> for OwnedOptionConfig {
+  fn from(option_config: OptionConfig<'_>) -> Self {
+    Self {
+      brief_description: option_config
+        .brief_description
+        .map(|brief_description: &str| brief_description.to_string()),
+      display_order: option_config.display_order,
+      heading: option_config
+        .heading
+        .map(|heading: &str| heading.to_string()),
+      long_description: option_config
+        .long_description
+        .map(|long_description: &str| long_description.to_string()),
+      parse_option_config: option_config.parse_option_config.into(),
+      value_name: option_config
+        .value_name
+        .map(|value_name: &str| value_name.to_string()),
+    }
+  }
+}
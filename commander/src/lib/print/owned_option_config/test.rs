@@ -0,0 +1,77 @@
+//==============================================================================
+//! Unit tests for module owned_option_config
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::print::app_info::AppInfo;
+use crate::print::help_info::HelpInfo;
+
+use super::*;
+
+const TEST_OPTION_CONFIG_0: OptionConfig = OptionConfig::long("TEST")
+  .short('T')
+  .help("TEST_BRIEF_DESCRIPTION");
+
+#[test]
+fn test_as_option_configs_0() {
+  // Simulates options loaded at runtime
+  let test_owned_option_configs: Vec<OwnedOptionConfig> =
+    vec![TEST_OPTION_CONFIG_0.into()];
+
+  let option_configs: Vec<OptionConfig> =
+    OwnedOptionConfig::as_option_configs(&test_owned_option_configs);
+
+  let help_info: HelpInfo = HelpInfo {
+    app_info: &AppInfo {
+      about: None,
+      authors: &[],
+      bug_report: None,
+      contact: None,
+      copyright: None,
+      homepage: None,
+      license: None,
+      long_about: None,
+      name: None,
+      version: None,
+    },
+    arg_options: &option_configs,
+    command_name: None,
+    parse_groups: &[],
+    positionals: &[],
+    sort_options: false,
+    subcommands: &[],
+    usage: None,
+    version_options: false,
+  };
+
+  assert!(
+    help_info
+      .make_print_string_with_width(80)
+      .contains("  -T, --TEST  TEST_BRIEF_DESCRIPTION\n")
+  );
+}
+
+#[test]
+fn test_from_0() {
+  let test_owned_option_config: OwnedOptionConfig = TEST_OPTION_CONFIG_0.into();
+
+  let actual: OptionConfig = test_owned_option_config.as_option_config();
+
+  assert_eq!(
+    actual.brief_description,
+    TEST_OPTION_CONFIG_0.brief_description
+  );
+
+  assert_eq!(
+    actual.parse_option_config,
+    TEST_OPTION_CONFIG_0.parse_option_config
+  );
+}
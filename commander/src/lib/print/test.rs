@@ -2,16 +2,20 @@
 //! Unit tests for module print
 //!
 //! # Metadata
-//! - Copyright: &copy; 2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2024-08-15
-//! - Updated: 2024-08-15
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
 use super::*;
+use crate::parse::parse_error::ParseError;
+use crate::parse::parse_found::ParseFound;
+use crate::parse::parse_input::ParseInput;
+use crate::parse::parse_warning::ParseWarning;
 use std::sync::LazyLock;
 
 static TEST_PARSE_OUTPUT_0: LazyLock<ParseOutput> =
@@ -22,6 +26,7 @@ static TEST_PARSE_OUTPUT_0: LazyLock<ParseOutput> =
       name_long: "unknown".to_string(),
     },
     known: None,
+    suggestions: Vec::new(),
     value: None,
   });
 
@@ -33,9 +38,27 @@ const TEST_PARSE_OUTPUT_1: ParseOutput = ParseOutput {
     name_short: 'u',
   },
   known: None,
+  suggestions: Vec::new(),
   value: None,
 };
 
+#[test]
+fn test_format_config_issue_0() {
+  let test_config_issue: ParseConfigIssue =
+    ParseConfigIssue::DuplicateNameShort {
+      first_id: "TEST_ID_0".to_string(),
+      id: "TEST_ID_1".to_string(),
+      name_short: 'n',
+    };
+
+  let expected: &str =
+    "Short name 'n' of option \"TEST_ID_1\" already used by \"TEST_ID_0\"";
+
+  let actual: String = format_config_issue(&test_config_issue);
+
+  assert_eq!(actual, expected);
+}
+
 #[test]
 fn test_format_unknown_option_0() {
   let expected: &str = "Unknown option at argument index 1: \"unknown\"";
@@ -54,3 +77,365 @@ fn test_format_unknown_option_1() {
 
   assert_eq!(&actual, expected);
 }
+
+#[test]
+fn test_format_unknown_option_2() {
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: None,
+    found: ParseFound::Long {
+      arg_index: 1,
+      name_long: "nmae".to_string(),
+    },
+    known: None,
+    suggestions: vec!["--name".to_string()],
+    value: None,
+  };
+
+  let expected: &str =
+    "Unknown option at argument index 1: \"nmae\", did you mean --name?";
+
+  let actual: String = format_unknown_option(&test_parse_output);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_rule_error_0() {
+  let test_rule_error: ParseRuleError = ParseRuleError::MissingOptions {
+    ids: vec![
+      "TEST_ID_0".to_string(),
+      "TEST_ID_1".to_string(),
+    ],
+    names: vec![
+      "--TEST".to_string(),
+      "-T".to_string(),
+    ],
+  };
+
+  let expected: &str = "Missing required options: --TEST, -T";
+
+  let actual: String = format_rule_error(&test_rule_error);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_rule_error_1() {
+  let test_rule_error: ParseRuleError = ParseRuleError::Conflict {
+    conflicting_found: ParseFound::Long {
+      arg_index: 2,
+      name_long: "verbose".to_string(),
+    },
+    conflicting_id: "TEST_ID_1".to_string(),
+    found: ParseFound::Short {
+      arg_index: 1,
+      char_index: 0,
+      name_short: 'q',
+    },
+    id: "TEST_ID_0".to_string(),
+  };
+
+  let expected: &str = "Option at argument index 1 character index 0: 'q' \
+    conflicts with option at argument index 2: \"verbose\"";
+
+  let actual: String = format_rule_error(&test_rule_error);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_rule_error_2() {
+  let test_rule_error: ParseRuleError = ParseRuleError::RequirementMissing {
+    found: ParseFound::Implied {
+      arg_index: 1,
+      implied_by: "ci".to_string(),
+      name: "--key".to_string(),
+    },
+    id: "TEST_ID_0".to_string(),
+    required_id: "TEST_ID_1".to_string(),
+    required_name: "--cert".to_string(),
+  };
+
+  let expected: &str = "Option at argument index 1: \"key\" implied by \"ci\" \
+    requires missing option --cert";
+
+  let actual: String = format_rule_error(&test_rule_error);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_rule_error_3() {
+  let test_rule_error: ParseRuleError = ParseRuleError::GroupMissing {
+    group_id: "source".to_string(),
+    names: vec![
+      "--file".to_string(),
+      "--url".to_string(),
+      "--stdin".to_string(),
+    ],
+  };
+
+  let expected: &str =
+    "Missing an option from group \"source\": (--file | --url | --stdin)";
+
+  let actual: String = format_rule_error(&test_rule_error);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_rule_error_4() {
+  let test_rule_error: ParseRuleError = ParseRuleError::GroupMultiple {
+    founds: vec![
+      ParseFound::Long {
+        arg_index: 1,
+        name_long: "file".to_string(),
+      },
+      ParseFound::Long {
+        arg_index: 2,
+        name_long: "url".to_string(),
+      },
+    ],
+    group_id: "source".to_string(),
+    names: vec![
+      "--file".to_string(),
+      "--url".to_string(),
+    ],
+  };
+
+  let expected: &str = "Only one option from group \"source\" allowed: \
+    (--file | --url) but found at argument index 1: \"file\" \
+    and at argument index 2: \"url\"";
+
+  let actual: String = format_rule_error(&test_rule_error);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_error_option_0() {
+  let test_parse_output: ParseOutput = ParseOutput {
+    error: Some(ParseError::DuplicateOption {
+      first_arg_index: 1,
+    }),
+    found: ParseFound::Long {
+      arg_index: 3,
+      name_long: "name".to_string(),
+    },
+    known: Some("name".to_string()),
+    suggestions: Vec::new(),
+    value: Some("B".to_string()),
+  };
+
+  let expected: &str =
+    "Duplicate of argument index 1 for option at argument index 3: \"name\"";
+
+  let actual: String = format_error_option(&test_parse_output);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_report_0() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![
+      TEST_PARSE_OUTPUT_0.clone(),
+      ParseOutput {
+        error: Some(ParseError::RequiredValueMissing),
+        found: ParseFound::Long {
+          arg_index: 2,
+          name_long: "name".to_string(),
+        },
+        known: Some("name".to_string()),
+        suggestions: Vec::new(),
+        value: None,
+      },
+    ],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  let expected: &str = "Required value missing for option at argument index \
+    2: \"name\"\nUnknown option at argument index 1: \"unknown\"\n";
+
+  let actual: String = format_report(&test_parse_results);
+
+  assert_eq!(&actual, expected);
+}
+
+#[test]
+fn test_format_report_1() {
+  let test_parse_results: ParseResults = ParseResults::default();
+
+  let actual: String = format_report(&test_parse_results);
+
+  assert!(actual.is_empty());
+}
+
+#[test]
+fn test_format_report_2() {
+  let test_args: Vec<String> = vec![
+    "test".to_string(),
+    "--unknown=".to_string(),
+  ];
+
+  let test_parse_results: ParseResults =
+    ParseInput::new(&test_args, &[]).parse();
+
+  let actual: String = format_report(&test_parse_results);
+
+  assert_eq!(actual, "Unknown option at argument index 1: \"unknown\"\n");
+}
+
+#[test]
+fn test_print_report_to_0() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: vec![TEST_PARSE_OUTPUT_1],
+    rule_errors: Vec::new(),
+    warnings: Vec::new(),
+  };
+
+  let mut buffer: Vec<u8> = Vec::new();
+
+  let reported: bool =
+    print_report_to(&test_parse_results, &mut buffer).unwrap();
+
+  let actual: String = String::from_utf8(buffer).unwrap();
+
+  assert!(reported);
+
+  assert_eq!(
+    actual,
+    "Unknown option at argument index 1 character index 2: 'u'\n"
+  );
+}
+
+#[test]
+fn test_write_unknown_option_to_0() {
+  let mut actual: String = String::new();
+
+  write_unknown_option_to(&TEST_PARSE_OUTPUT_0, &mut actual).unwrap();
+
+  assert_eq!(actual, "Unknown option at argument index 1: \"unknown\"\n");
+}
+
+#[test]
+fn test_format_report_as_0() {
+  let test_parse_results: ParseResults = ParseResults::default();
+
+  let actual: String =
+    format_report_as(&test_parse_results, OutputFormat::Json);
+
+  assert!(actual.is_empty());
+}
+
+#[test]
+fn test_format_report_as_1() {
+  let test_parse_results: ParseResults = ParseResults {
+    parse_outputs: Vec::new(),
+    rule_errors: Vec::new(),
+    warnings: vec![
+      ParseWarning::Deprecated {
+        found: ParseFound::Long {
+          arg_index: 1,
+          name_long: "out".to_string(),
+        },
+        id: "out".to_string(),
+        replacement: None,
+      },
+    ],
+  };
+
+  let expected_json: &str = "[{\"kind\":\"deprecated\",\"id\":\"out\",\
+    \"name\":\"out\",\"arg_index\":1,\"char_index\":null,\
+    \"replacement\":null,\"message\":\"Deprecated option at argument \
+    index 1: \\\"out\\\"\"}]\n";
+
+  let expected_text: &str =
+    "warning: Deprecated option at argument index 1: \"out\"\n";
+
+  assert_eq!(
+    format_report_as(&test_parse_results, OutputFormat::Json),
+    expected_json
+  );
+
+  assert_eq!(
+    format_report_as(&test_parse_results, OutputFormat::Text),
+    expected_text
+  );
+
+  let mut buffer: Vec<u8> = Vec::new();
+
+  assert!(!print_report_to(&test_parse_results, &mut buffer).unwrap());
+}
+
+#[test]
+fn test_write_report_as_to_0() {
+  let test_args: Vec<String> = vec![
+    "test".to_string(),
+    "--unknown=".to_string(),
+  ];
+
+  let test_parse_results: ParseResults =
+    ParseInput::new(&test_args, &[]).parse();
+
+  let expected: &str = "[{\"kind\":\"unknown_option\",\"id\":null,\
+    \"name\":\"unknown\",\"arg_index\":1,\"char_index\":null,\
+    \"value\":null,\"message\":\"Unknown option at argument index 1: \
+    \\\"unknown\\\"\"}]\n";
+
+  let mut actual: String = String::new();
+
+  let reported: bool =
+    write_report_as_to(&test_parse_results, OutputFormat::Json, &mut actual)
+      .unwrap();
+
+  assert!(reported);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_format_warnings_0() {
+  let test_warnings: Vec<ParseWarning> = vec![
+    ParseWarning::Deprecated {
+      found: ParseFound::Long {
+        arg_index: 1,
+        name_long: "out".to_string(),
+      },
+      id: "out".to_string(),
+      replacement: Some("--output".to_string()),
+    },
+  ];
+
+  let expected: &str = "warning: Deprecated option at argument index 1: \
+    \"out\", use --output instead\n";
+
+  assert_eq!(format_warnings(&test_warnings), expected);
+
+  assert_eq!(format_warnings(&[]), "");
+}
+
+#[test]
+fn test_write_warnings_to_0() {
+  let test_warnings: Vec<ParseWarning> = vec![
+    ParseWarning::Deprecated {
+      found: ParseFound::Short {
+        arg_index: 2,
+        char_index: 0,
+        name_short: 'o',
+      },
+      id: "out".to_string(),
+      replacement: None,
+    },
+  ];
+
+  let mut actual: String = String::new();
+
+  write_warnings_to(&test_warnings, &mut actual).unwrap();
+
+  assert_eq!(
+    actual,
+    "warning: Deprecated option at argument index 2 character index 0: 'o'\n"
+  );
+}
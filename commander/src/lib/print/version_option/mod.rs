@@ -0,0 +1,201 @@
+//==============================================================================
+//! Module for the built-in -\-version option
+//!
+//! - The -V and -\-version option shows the application name and version
+//! - Adding -\-verbose also shows the application metadata and the build
+//!   information supplied by the application
+//! - Like -\-help, the version is shown instead of reporting other problems
+//! - The derive macro and the option_configs macro include these options by
+//!   default; otherwise add them using add_version_options
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::fmt;
+use std::io;
+use std::process;
+
+use crate::parse::duplicate_policy::DuplicatePolicy;
+use crate::parse::parse_results::ParseResults;
+
+use super::app_info::AppInfo;
+use super::build_info::BuildInfo;
+use super::option_config::OptionConfig;
+
+#[cfg(test)]
+mod test;
+
+pub const VERBOSE_ID: &str = "verbose";
+
+pub const VERSION_ID: &str = "version";
+
+const VERSION_HELP: &str = "Show version information";
+
+//------------------------------------------------------------------------------
+/// The -\-verbose option which adds the build information to -\-version
+//------------------------------------------------------------------------------
+pub const VERBOSE_OPTION_CONFIG: OptionConfig<'static> =
+  OptionConfig::long(VERBOSE_ID)
+    .duplicate_policy(DuplicatePolicy::LastWins)
+    .help("Show the build information with --version");
+
+//------------------------------------------------------------------------------
+/// The -V and -\-version option which shows the version
+//------------------------------------------------------------------------------
+pub const VERSION_OPTION_CONFIG: OptionConfig<'static> =
+  OptionConfig::long(VERSION_ID)
+    .short('V')
+    .duplicate_policy(DuplicatePolicy::LastWins)
+    .help(VERSION_HELP);
+
+//------------------------------------------------------------------------------
+/// The options followed by the -\-version and -\-verbose options
+///
+/// - Each is skipped if it is already in the options, such as from the
+///   option_configs macro
+/// - The -\-version option is added without -V if another option uses -V
+/// - An option using the id or long name of either is not replaced: the clash
+///   is reported as a config issue by ParseInput::validate, which
+///   ParseInput::parse checks in debug builds
+//------------------------------------------------------------------------------
+pub fn add_version_options<'a>(
+  arg_options: &[OptionConfig<'a>]
+) -> Vec<OptionConfig<'a>> {
+  let mut version_options: Vec<OptionConfig> = arg_options.to_vec();
+
+  let version_option_config: OptionConfig =
+    if arg_options.iter().any(|arg_option: &OptionConfig| {
+      arg_option.parse_option_config.id != VERSION_ID
+        && arg_option.parse_option_config.name.get_name_short() == Some('V')
+    }) {
+      OptionConfig::long(VERSION_ID)
+        .duplicate_policy(DuplicatePolicy::LastWins)
+        .help(VERSION_HELP)
+    } else {
+      VERSION_OPTION_CONFIG
+    };
+
+  for option_config in [
+    version_option_config,
+    VERBOSE_OPTION_CONFIG,
+  ] {
+    if !arg_options.iter().any(|arg_option: &OptionConfig| {
+      arg_option.parse_option_config == option_config.parse_option_config
+    }) {
+      version_options.push(option_config);
+    }
+  }
+
+  version_options
+}
+
+//------------------------------------------------------------------------------
+/// The version to show if the -\-version option is present
+///
+/// - Includes the application metadata and the build information if the
+///   -\-verbose option is present
+/// - Returns None if the -\-version option is not present
+//------------------------------------------------------------------------------
+pub fn make_version_string_if_requested(
+  app_info: &AppInfo,
+  build_info: &BuildInfo,
+  parse_results: &ParseResults,
+) -> Option<String> {
+  if !parse_results.is_present(VERSION_ID) {
+    return None;
+  }
+
+  if parse_results.is_present(VERBOSE_ID) {
+    Some(app_info.make_verbose_version_string(build_info))
+  } else {
+    Some(app_info.make_version_string())
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Prints the version if the -\-version option is present
+///
+/// - See make_version_string_if_requested for what is printed
+/// - Returns true if the version was printed
+//------------------------------------------------------------------------------
+pub fn print_version_if_requested(
+  app_info: &AppInfo,
+  build_info: &BuildInfo,
+  parse_results: &ParseResults,
+) -> bool {
+  print_version_if_requested_to(
+    app_info,
+    build_info,
+    parse_results,
+    &mut io::stdout(),
+  )
+  .expect("failed printing to stdout")
+}
+
+//------------------------------------------------------------------------------
+/// Prints the version to an output stream if the -\-version option is present
+///
+/// - Returns true if the version was printed
+//------------------------------------------------------------------------------
+pub fn print_version_if_requested_to(
+  app_info: &AppInfo,
+  build_info: &BuildInfo,
+  parse_results: &ParseResults,
+  writer: &mut dyn io::Write,
+) -> io::Result<bool> {
+  let Some(version_string) =
+    make_version_string_if_requested(app_info, build_info, parse_results)
+  else {
+    return Ok(false);
+  };
+
+  writer.write_all(version_string.as_bytes())?;
+
+  Ok(true)
+}
+
+//------------------------------------------------------------------------------
+/// Prints the version and exits successfully if -\-version is present
+///
+/// - Call before reporting the parse errors so that -\-version short-circuits
+///   like -\-help
+//------------------------------------------------------------------------------
+pub fn version_and_exit(
+  app_info: &AppInfo,
+  build_info: &BuildInfo,
+  parse_results: &ParseResults,
+) {
+  if print_version_if_requested(app_info, build_info, parse_results) {
+    process::exit(0);
+  }
+}
+
+//------------------------------------------------------------------------------
+/// Writes the version to a string or formatter if the -\-version option is
+/// present
+///
+/// - Returns true if the version was written
+//------------------------------------------------------------------------------
+pub fn write_version_if_requested_to(
+  app_info: &AppInfo,
+  build_info: &BuildInfo,
+  parse_results: &ParseResults,
+  writer: &mut dyn fmt::Write,
+) -> Result<bool, fmt::Error> {
+  let Some(version_string) =
+    make_version_string_if_requested(app_info, build_info, parse_results)
+  else {
+    return Ok(false);
+  };
+
+  writer.write_str(&version_string)?;
+
+  Ok(true)
+}
@@ -0,0 +1,223 @@
+//==============================================================================
+//! Unit tests for module version_option
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use crate::parse::parse_config_issue::ParseConfigIssue;
+use crate::parse::parse_input::ParseInput;
+use crate::parse::parse_option_config::ParseOptionConfig;
+
+use super::*;
+
+fn make_names(arg_options: &[OptionConfig]) -> Vec<String> {
+  arg_options
+    .iter()
+    .map(|arg_option: &OptionConfig| {
+      arg_option.parse_option_config.name.make_hyphenated_name()
+    })
+    .collect()
+}
+
+fn parse(args: &[&str]) -> ParseResults {
+  let test_args: Vec<String> =
+    args.iter().map(|arg: &&str| arg.to_string()).collect();
+
+  let arg_options: Vec<OptionConfig> =
+    add_version_options(&[OptionConfig::long("quiet").short('q')]);
+
+  let parse_option_configs: Vec<&ParseOptionConfig> = arg_options
+    .iter()
+    .map(|arg_option: &OptionConfig| &arg_option.parse_option_config)
+    .collect();
+
+  ParseInput::from_args_without_program_name(&test_args, &parse_option_configs)
+    .parse()
+}
+
+#[test]
+fn test_add_version_options_0() {
+  let actual: Vec<OptionConfig> =
+    add_version_options(&[OptionConfig::long("quiet").short('q')]);
+
+  assert_eq!(
+    make_names(&actual),
+    vec![
+      "--quiet",
+      "--version",
+      "--verbose"
+    ]
+  );
+
+  assert_eq!(
+    actual[1].parse_option_config.name.get_name_short(),
+    Some('V')
+  );
+}
+
+#[test]
+fn test_add_version_options_1() {
+  let actual: Vec<OptionConfig> =
+    add_version_options(&[OptionConfig::long("verify").short('V')]);
+
+  assert_eq!(
+    make_names(&actual),
+    vec![
+      "--verify",
+      "--version",
+      "--verbose"
+    ]
+  );
+
+  assert_eq!(actual[1].parse_option_config.name.get_name_short(), None);
+}
+
+#[test]
+fn test_add_version_options_2() {
+  let actual: Vec<OptionConfig> = add_version_options(&[
+    OptionConfig::long("quiet"),
+    VERSION_OPTION_CONFIG,
+    VERBOSE_OPTION_CONFIG,
+  ]);
+
+  assert_eq!(
+    make_names(&actual),
+    vec![
+      "--quiet",
+      "--version",
+      "--verbose"
+    ]
+  );
+}
+
+#[test]
+fn test_add_version_options_3() {
+  let arg_options: Vec<OptionConfig> =
+    add_version_options(&[OptionConfig::long("verbose").short('v')]);
+
+  let parse_option_configs: Vec<&ParseOptionConfig> = arg_options
+    .iter()
+    .map(|arg_option: &OptionConfig| &arg_option.parse_option_config)
+    .collect();
+
+  let actual: Vec<ParseConfigIssue> =
+    ParseInput::from_args_without_program_name(&[], &parse_option_configs)
+      .validate();
+
+  assert!(actual.contains(&ParseConfigIssue::DuplicateId {
+    id: VERBOSE_ID.to_string(),
+  }));
+}
+
+const TEST_APP_INFO: AppInfo = AppInfo {
+  about: None,
+  authors: &["TEST_AUTHOR"],
+  bug_report: None,
+  contact: None,
+  copyright: None,
+  homepage: None,
+  license: Some("MIT"),
+  long_about: None,
+  name: Some("TEST_NAME"),
+  version: Some("1.2.3"),
+};
+
+const TEST_BUILD_INFO: BuildInfo = BuildInfo {
+  features: &[],
+  target: Some("TEST_TARGET"),
+  version: Some("4.5.6"),
+};
+
+#[test]
+fn test_make_version_string_if_requested_0() {
+  let actual: Option<String> = make_version_string_if_requested(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&["-q"]),
+  );
+
+  assert_eq!(actual, None);
+}
+
+#[test]
+fn test_make_version_string_if_requested_1() {
+  let actual: Option<String> = make_version_string_if_requested(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&[
+      "-V", "--bogus",
+    ]),
+  );
+
+  assert_eq!(actual.as_deref(), Some("TEST_NAME 1.2.3\n"));
+}
+
+#[test]
+fn test_make_version_string_if_requested_2() {
+  let actual: Option<String> = make_version_string_if_requested(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&[
+      "--version",
+      "--verbose",
+    ]),
+  );
+
+  let expected: &str = "TEST_NAME 1.2.3\n\
+    authors: TEST_AUTHOR\n\
+    license: MIT\n\
+    crate version: 4.5.6\n\
+    target: TEST_TARGET\n";
+
+  assert_eq!(actual.as_deref(), Some(expected));
+}
+
+#[test]
+fn test_print_version_if_requested_0() {
+  assert!(!print_version_if_requested(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&["-q"]),
+  ));
+}
+
+#[test]
+fn test_print_version_if_requested_to_0() {
+  let mut buffer: Vec<u8> = Vec::new();
+
+  let printed: bool = print_version_if_requested_to(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&["--version"]),
+    &mut buffer,
+  )
+  .unwrap();
+
+  assert!(printed);
+
+  assert_eq!(String::from_utf8(buffer).unwrap(), "TEST_NAME 1.2.3\n");
+}
+
+#[test]
+fn test_write_version_if_requested_to_0() {
+  let mut actual: String = String::new();
+
+  let written: bool = write_version_if_requested_to(
+    &TEST_APP_INFO,
+    &TEST_BUILD_INFO,
+    &parse(&["-q"]),
+    &mut actual,
+  )
+  .unwrap();
+
+  assert!(!written);
+
+  assert!(actual.is_empty());
+}
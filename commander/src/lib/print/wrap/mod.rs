@@ -0,0 +1,212 @@
+//==============================================================================
+//! Module for wrapping help text to the terminal width
+//!
+//! - The width is from the COLUMNS environment variable if set
+//! - Otherwise the width is queried from the terminal if the optional cargo
+//!   feature "terminal_size" is enabled
+//! - Otherwise the width defaults to DEFAULT_WIDTH
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::env;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[cfg(test)]
+mod test;
+
+//------------------------------------------------------------------------------
+/// The width when the terminal width is unknown
+//------------------------------------------------------------------------------
+pub const DEFAULT_WIDTH: usize = 80;
+
+//------------------------------------------------------------------------------
+/// The minimum width for a description beside the option prefix
+///
+/// - A description is shown on the next line instead if it would be narrower
+//------------------------------------------------------------------------------
+pub const MIN_DESCRIPTION_WIDTH: usize = 20;
+
+//------------------------------------------------------------------------------
+/// The indentation of a description shown on the line after the option prefix
+//------------------------------------------------------------------------------
+pub const NEXT_LINE_INDENT: usize = 8;
+
+//------------------------------------------------------------------------------
+/// The number of terminal columns used to display the text
+///
+/// - Wide characters such as CJK characters use two columns
+/// - Combining and other zero-width characters use no columns
+//------------------------------------------------------------------------------
+pub fn display_width(text: &str) -> usize {
+  UnicodeWidthStr::width(text)
+}
+
+//------------------------------------------------------------------------------
+/// The terminal width from COLUMNS, a terminal query, or the default
+//------------------------------------------------------------------------------
+pub fn get_terminal_width() -> usize {
+  let columns_option: Option<usize> = env::var("COLUMNS")
+    .ok()
+    .and_then(|columns: String| columns.trim().parse::<usize>().ok())
+    .filter(|columns: &usize| *columns > 0);
+
+  if let Some(columns) = columns_option {
+    return columns;
+  }
+
+  query_terminal_width().unwrap_or(DEFAULT_WIDTH)
+}
+
+//------------------------------------------------------------------------------
+/// The prefix followed by the description wrapped to a column
+///
+/// - The description column is the display width before the description
+/// - Continuation lines are indented to the description column
+/// - The description starts on the next line, indented by NEXT_LINE_INDENT,
+///   if the description column would leave less than MIN_DESCRIPTION_WIDTH
+//------------------------------------------------------------------------------
+pub fn make_column_string(
+  prefix: &str,
+  description: &str,
+  description_column: usize,
+  width: usize,
+) -> String {
+  if description_column + MIN_DESCRIPTION_WIDTH > width {
+    let indent: String = " ".repeat(NEXT_LINE_INDENT);
+
+    let lines: Vec<String> =
+      wrap_text(description, width.saturating_sub(NEXT_LINE_INDENT).max(1));
+
+    let indented_lines: Vec<String> = lines
+      .iter()
+      .map(|line: &String| format!("{indent}{line}"))
+      .collect();
+
+    return format!("{}\n{}", prefix, indented_lines.join("\n"));
+  }
+
+  let lines: Vec<String> = wrap_text(description, width - description_column);
+
+  let mut column_string: String = prefix.to_string();
+
+  column_string.push_str(
+    &" ".repeat(description_column.saturating_sub(display_width(prefix))),
+  );
+
+  for (index, line) in lines.iter().enumerate() {
+    if index > 0 {
+      column_string.push('\n');
+
+      column_string.push_str(&" ".repeat(description_column));
+    }
+
+    column_string.push_str(line);
+  }
+
+  column_string
+}
+
+//------------------------------------------------------------------------------
+/// Splits the text into lines no wider than the width, breaking at spaces
+///
+/// - The width is in terminal columns as measured by display_width
+/// - Also breaks before and after wide characters such as CJK characters,
+///   which are written without spaces between the words
+/// - A word wider than the width without wide characters is put on a line by
+///   itself
+/// - Returns one empty line if the text is empty
+//------------------------------------------------------------------------------
+pub fn wrap_text(
+  text: &str,
+  width: usize,
+) -> Vec<String> {
+  let mut lines: Vec<String> = Vec::new();
+
+  let mut line: String = String::new();
+
+  let mut line_width: usize = 0;
+
+  for word in text.split_whitespace() {
+    for (index, piece) in split_at_wide_chars(word).into_iter().enumerate() {
+      let separator_width: usize = usize::from(index == 0 && line_width > 0);
+
+      let piece_width: usize = display_width(piece);
+
+      if line_width > 0 && line_width + separator_width + piece_width > width {
+        lines.push(line);
+
+        line = String::new();
+
+        line_width = 0;
+      } else if separator_width > 0 {
+        line.push(' ');
+
+        line_width += 1;
+      }
+
+      line.push_str(piece);
+
+      line_width += piece_width;
+    }
+  }
+
+  lines.push(line);
+
+  lines
+}
+
+// -----------------------------------------------------------------------------
+// private functions
+// -----------------------------------------------------------------------------
+
+//------------------------------------------------------------------------------
+/// Splits a word into each wide character and the runs of other characters
+//------------------------------------------------------------------------------
+fn split_at_wide_chars(word: &str) -> Vec<&str> {
+  let mut pieces: Vec<&str> = Vec::new();
+
+  let mut start: usize = 0;
+
+  for (index, c) in word.char_indices() {
+    if UnicodeWidthChar::width(c).unwrap_or(0) < 2 {
+      continue;
+    }
+
+    if start < index {
+      pieces.push(&word[start..index]);
+    }
+
+    let end: usize = index + c.len_utf8();
+
+    pieces.push(&word[index..end]);
+
+    start = end;
+  }
+
+  if start < word.len() {
+    pieces.push(&word[start..]);
+  }
+
+  pieces
+}
+
+#[cfg(feature = "terminal_size")]
+fn query_terminal_width() -> Option<usize> {
+  terminal_size::terminal_size()
+    .map(|(width, _height)| usize::from(width.0))
+    .filter(|width: &usize| *width > 0)
+}
+
+#[cfg(not(feature = "terminal_size"))]
+fn query_terminal_width() -> Option<usize> {
+  None
+}
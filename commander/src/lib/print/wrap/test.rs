@@ -0,0 +1,81 @@
+//==============================================================================
+//! Unit tests for module wrap
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use super::*;
+
+#[test]
+fn test_display_width_0() {
+  assert_eq!(display_width("name"), 4);
+
+  assert_eq!(display_width("名前"), 4);
+
+  assert_eq!(display_width("cafe\u{301}"), 4);
+}
+
+#[test]
+fn test_wrap_text_0() {
+  let expected: Vec<String> = vec![
+    "The quick".to_string(),
+    "brown fox".to_string(),
+    "jumps".to_string(),
+  ];
+
+  let actual: Vec<String> = wrap_text("The quick brown fox jumps", 10);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_wrap_text_1() {
+  let expected: Vec<String> = vec![
+    "a".to_string(),
+    "unbreakable".to_string(),
+    "b".to_string(),
+  ];
+
+  let actual: Vec<String> = wrap_text("a unbreakable b", 5);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_wrap_text_2() {
+  let actual: Vec<String> = wrap_text("", 10);
+
+  assert_eq!(actual, vec![String::new()]);
+}
+
+#[test]
+fn test_wrap_text_3() {
+  let expected: Vec<String> = vec![
+    "名前を入力".to_string(),
+    "してくださ".to_string(),
+    "い".to_string(),
+  ];
+
+  let actual: Vec<String> = wrap_text("名前を入力してください", 10);
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_wrap_text_4() {
+  let expected: Vec<String> = vec![
+    "Use 名前".to_string(),
+    "(name)".to_string(),
+  ];
+
+  let actual: Vec<String> = wrap_text("Use 名前(name)", 8);
+
+  assert_eq!(actual, expected);
+}
@@ -0,0 +1,22 @@
+//==============================================================================
+//! Build script which passes the target triple to the examples
+//!
+//! - Shown by the example applications for -\-version -\-verbose
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::env;
+
+fn main() {
+  let target: String = env::var("TARGET").unwrap_or_default();
+
+  println!("cargo:rustc-env=TARGET={target}");
+}
@@ -5,7 +5,7 @@
 //! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-01-15
-//! - Updated: 2026-04-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -68,6 +68,7 @@ pub fn parse_option_values_using_clap() -> OptionValues {
 
   OptionValues {
     errors,
+    help_long: false,
     help_wanted: false,
     interactive: Ok(interactive),
     name_option,
@@ -5,7 +5,7 @@
 //! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-01-15
-//! - Updated: 2026-04-18
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,9 +14,12 @@
 use std::env;
 
 use commander::parse::parse_error::ParseError;
-use commander::parse::parse_iterator::ParseIterator;
+use commander::parse::parse_found::ParseFound;
+use commander::parse::parse_input::ParseInput;
 use commander::parse::parse_option_config::ParseOptionConfig;
 use commander::parse::parse_output::ParseOutput;
+use commander::parse::parse_results::ParseResults;
+use commander::print::version_option;
 use croftsoft_commander_examples::OptionValues;
 use croftsoft_commander_examples::constants::*;
 
@@ -36,32 +39,34 @@ fn main() {
 pub fn parse_option_values_using_commander() -> OptionValues {
   let args: Vec<String> = env::args().collect();
 
+  // The option_configs macro includes the -V, --version, and --verbose options
   let arg_option_vector: Vec<&ParseOptionConfig> = OPTION_CONFIGS
     .iter()
     .map(|config| &config.parse_option_config)
     .collect();
 
-  // TODO: Use a new(parse_option_configs) function
-  let mut parse_iterator = ParseIterator {
-    args: &args,
-    parse_option_configs: &arg_option_vector,
-    skip_arg: 1,
-    skip_char: 0,
-  };
+  let parse_input: ParseInput = ParseInput::new(&args, &arg_option_vector);
 
-  let mut errors: Vec<ParseOutput> = parse_iterator.parse_errors();
+  // The duplicate policies leave at most one output per option
+  let parse_results: ParseResults = parse_input.parse();
 
-  let mut parse_iterator = ParseIterator {
-    args: &args,
-    parse_option_configs: &arg_option_vector,
-    skip_arg: 1,
-    skip_char: 0,
-  };
+  // Shows the version instead of any errors, like the help option
+  version_option::version_and_exit(&APP_INFO, &BUILD_INFO, &parse_results);
 
-  let unknown: Vec<ParseOutput> = parse_iterator.parse_unknown();
+  let mut errors: Vec<ParseOutput> = parse_results.get_errors();
+
+  let unknown: Vec<ParseOutput> = parse_results.get_unknown();
 
   let help_wanted_parse_output_option: Option<ParseOutput> =
-    OPTION_CONFIG_H.parse_last(&args);
+    parse_results.get_last(ARG_HELP_ID);
+
+  let help_long: bool = matches!(
+    &help_wanted_parse_output_option,
+    Some(ParseOutput {
+      found: ParseFound::Long { .. },
+      ..
+    })
+  );
 
   let help_wanted: bool =
     if let Some(help_wanted_parse_output) = help_wanted_parse_output_option {
@@ -74,7 +79,7 @@ pub fn parse_option_values_using_commander() -> OptionValues {
     };
 
   let interactive_parse_output_option: Option<ParseOutput> =
-    OPTION_CONFIG_I.parse_last(&args);
+    parse_results.get_last(ARG_INTERACTIVE_ID);
 
   let interactive: Result<bool, ParseError> =
     if let Some(mut interactive_parse_output) = interactive_parse_output_option
@@ -85,9 +90,11 @@ pub fn parse_option_values_using_commander() -> OptionValues {
       match interactive_result {
         Ok(interactive) => Ok(interactive),
         Err(parse_error) => {
-          interactive_parse_output.error = Some(parse_error);
+          if interactive_parse_output.error.is_none() {
+            interactive_parse_output.error = Some(parse_error);
 
-          errors.push(interactive_parse_output);
+            errors.push(interactive_parse_output);
+          }
 
           Ok(false)
         },
@@ -99,7 +106,7 @@ pub fn parse_option_values_using_commander() -> OptionValues {
   // TODO: parse_option_type_string_with_default_value
 
   let name_parse_output_option: Option<ParseOutput> =
-    OPTION_CONFIG_N.parse_last(&args);
+    parse_results.get_last(ARG_NAME_ID);
 
   let name_option: Option<String> =
     if let Some(name_parse_output) = name_parse_output_option {
@@ -109,7 +116,7 @@ pub fn parse_option_values_using_commander() -> OptionValues {
     };
 
   let quiet_parse_output_option: Option<ParseOutput> =
-    OPTION_CONFIG_Q.parse_last(&args);
+    parse_results.get_last(ARG_QUIET_ID);
 
   // TODO: Show the user the parse error
   let quiet: bool = if let Some(quiet_parse_output) = quiet_parse_output_option
@@ -121,6 +128,7 @@ pub fn parse_option_values_using_commander() -> OptionValues {
 
   OptionValues {
     errors,
+    help_long,
     help_wanted,
     interactive,
     name_option,
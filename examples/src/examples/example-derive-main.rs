@@ -0,0 +1,104 @@
+//==============================================================================
+//! CroftSoft Commander derive macro usage example
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use std::env;
+
+use commander::parse::parse_results::ParseResults;
+use commander::print;
+use commander_derive::Commander;
+use croftsoft_commander_examples::OptionValues;
+
+//------------------------------------------------------------------------------
+/// Command-line arguments parser example
+//------------------------------------------------------------------------------
+#[derive(Commander, Debug)]
+#[commander(
+  app_name = "CroftSoft Commander Derive Example",
+  command_name = "example-derive",
+  contact = "David Wallace Croft <david@CroftSoft.com>",
+  copyright = "Copyright © 2026 CroftSoft Inc",
+  license = "MIT",
+  version = "0.2.0-SNAPSHOT"
+)]
+struct DeriveOptions {
+  /// Show command-line options
+  #[commander(short = 'h')]
+  help: bool,
+  /// true/false, defaults to true
+  #[commander(short = 'i')]
+  interactive: Option<bool>,
+  /// Any value not starting with a hyphen (-)
+  #[commander(short = 'n')]
+  name: Option<String>,
+  /// Suppress the exclamation mark
+  #[commander(short = 'q')]
+  quiet: bool,
+}
+
+//------------------------------------------------------------------------------
+/// Parses the options using the derive macro and then runs the application.
+//------------------------------------------------------------------------------
+fn main() {
+  let args: Vec<String> = env::args().collect();
+
+  // Prints the version and exits if -V or --version is present
+  let option_values: OptionValues = match DeriveOptions::parse(&args) {
+    Ok(derive_options) => {
+      if derive_options.help {
+        DeriveOptions::HELP_INFO.print();
+
+        return;
+      }
+
+      OptionValues {
+        errors: Vec::new(),
+        help_long: false,
+        help_wanted: false,
+        interactive: Ok(derive_options.interactive.unwrap_or(true)),
+        name_option: derive_options.name,
+        quiet: derive_options.quiet,
+        unknown: Vec::new(),
+      }
+    },
+    Err(parse_results) => {
+      // The parse results are returned with the help flag if a required
+      // option is missing
+      if parse_results.is_present("help") {
+        DeriveOptions::HELP_INFO.print();
+
+        return;
+      }
+
+      print::report_and_exit(&parse_results, print::EXIT_CODE_USAGE);
+
+      make_error_option_values(&parse_results)
+    },
+  };
+
+  croftsoft_commander_examples::main(option_values);
+}
+
+//------------------------------------------------------------------------------
+/// The option values for reporting the parse errors and unknown options
+//------------------------------------------------------------------------------
+fn make_error_option_values(parse_results: &ParseResults) -> OptionValues {
+  OptionValues {
+    errors: parse_results.get_errors(),
+    help_long: false,
+    help_wanted: false,
+    interactive: Ok(false),
+    name_option: None,
+    quiet: false,
+    unknown: parse_results.get_unknown(),
+  }
+}
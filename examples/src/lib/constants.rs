@@ -2,26 +2,37 @@
 //! CroftSoft Commander library usage example constants
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-01-15
-//! - Updated: 2024-07-13
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
-use commander::parse::parse_option_config::ParseOptionConfig;
-use commander::parse::parse_option_name::ParseOptionName;
-use commander::parse::value_usage::ValueUsage;
+use commander::option_configs;
+use commander::parse::duplicate_policy::DuplicatePolicy;
 use commander::print::app_info::AppInfo;
+use commander::print::build_info::BuildInfo;
 use commander::print::help_info::HelpInfo;
-use commander::print::option_config::OptionConfig;
 
 pub const APP_INFO_ABOUT: &str = "Command-line arguments parser example";
+pub const APP_INFO_AUTHORS: &[&str] =
+  &["David Wallace Croft <david@CroftSoft.com>"];
+pub const APP_INFO_BUG_REPORT: &str =
+  "https://github.com/david-wallace-croft/commander/issues";
 pub const APP_INFO_CONTACT: &str = "David Wallace Croft <david@CroftSoft.com>";
 pub const APP_INFO_COPYRIGHT: &str = "Copyright © 2022-2024 CroftSoft Inc";
+pub const APP_INFO_HOMEPAGE: &str =
+  "https://github.com/david-wallace-croft/commander";
+pub const APP_INFO_LICENSE: &str = "MIT";
+pub const APP_INFO_LONG_ABOUT: &str = "Command-line arguments parser example
+
+Greets the user by name, asking for the name first unless interactive mode \
+is turned off.";
 pub const APP_INFO_NAME: &str = "CroftSoft Commander Example";
+pub const APP_INFO_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const ARG_HELP_HELP: &str = "Show command-line options";
 pub const ARG_HELP_ID: &str = "help";
@@ -30,15 +41,22 @@ pub const ARG_HELP_NAME_SHORT: char = 'h';
 
 pub const ARG_INTERACTIVE_HELP: &str = "true/false, defaults to true";
 pub const ARG_INTERACTIVE_ID: &str = "interactive";
+pub const ARG_INTERACTIVE_LONG_HELP: &str = "Asks for the name before the \
+greeting, showing the name from --name as the default.
+
+Example: example-commander -i=false";
 pub const ARG_INTERACTIVE_NAME_LONG: &str = "interactive";
 pub const ARG_INTERACTIVE_NAME_SHORT: char = 'i';
+pub const ARG_INTERACTIVE_VALUE_NAME: &str = "BOOL";
 // TODO: This one is clap-only.
 pub const ARG_INTERACTIVE_TAKES_VALUE: bool = true;
 
 pub const ARG_NAME_HELP: &str = "Any value not starting with a hyphen (-)";
 pub const ARG_NAME_ID: &str = "name";
+pub const ARG_NAME_LONG_HELP: &str = "The name to greet, defaulting to World.";
 pub const ARG_NAME_NAME_LONG: &str = "name";
 pub const ARG_NAME_NAME_SHORT: char = 'n';
+pub const ARG_NAME_VALUE_NAME: &str = "NAME";
 // TODO: This one is clap-only.
 pub const ARG_NAME_TAKES_VALUE: bool = true;
 
@@ -48,72 +66,77 @@ pub const ARG_QUIET_ID: &str = "quiet";
 pub const ARG_QUIET_NAME_LONG: &str = "quiet";
 pub const ARG_QUIET_NAME_SHORT: char = 'q';
 
+pub const HELP_INFO_COMMAND_NAME: &str = "example-commander";
+
 pub const NAME_DEFAULT: &str = "World";
 pub const NAME_PROMPT: &str = "What is your name?";
 
 pub const APP_INFO: AppInfo = AppInfo {
   about: Some(APP_INFO_ABOUT),
+  authors: APP_INFO_AUTHORS,
+  bug_report: Some(APP_INFO_BUG_REPORT),
   contact: Some(APP_INFO_CONTACT),
   copyright: Some(APP_INFO_COPYRIGHT),
+  homepage: Some(APP_INFO_HOMEPAGE),
+  license: Some(APP_INFO_LICENSE),
+  long_about: Some(APP_INFO_LONG_ABOUT),
   name: Some(APP_INFO_NAME),
+  version: Some(APP_INFO_VERSION),
 };
 
-pub const OPTION_CONFIG_H: OptionConfig = OptionConfig {
-  brief_description: Some(ARG_HELP_HELP),
-  parse_option_config: ParseOptionConfig {
-    id: ARG_HELP_ID,
-    name: ParseOptionName::Both {
-      name_long: ARG_HELP_NAME_LONG,
-      name_short: ARG_HELP_NAME_SHORT,
-    },
-    value_usage: ValueUsage::Verboten,
-  },
+// The TARGET environment variable is set by the build script
+pub const BUILD_INFO: BuildInfo = BuildInfo {
+  features: &[],
+  target: Some(env!("TARGET")),
+  version: Some(env!("CARGO_PKG_VERSION")),
 };
 
-pub const OPTION_CONFIG_I: OptionConfig = OptionConfig {
-  brief_description: Some(ARG_INTERACTIVE_HELP),
-  parse_option_config: ParseOptionConfig {
+option_configs! {
+  pub const OPTION_CONFIGS;
+
+  pub const OPTION_CONFIG_H = ARG_HELP_NAME_LONG => {
+    id: ARG_HELP_ID,
+    short: ARG_HELP_NAME_SHORT,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    help: ARG_HELP_HELP,
+  };
+
+  pub const OPTION_CONFIG_I = ARG_INTERACTIVE_NAME_LONG => {
     id: ARG_INTERACTIVE_ID,
-    name: ParseOptionName::Both {
-      name_long: ARG_INTERACTIVE_NAME_LONG,
-      name_short: ARG_INTERACTIVE_NAME_SHORT,
-    },
-    value_usage: ValueUsage::Optional,
-  },
-};
+    short: ARG_INTERACTIVE_NAME_SHORT,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    optional_value,
+    value_name: ARG_INTERACTIVE_VALUE_NAME,
+    help: ARG_INTERACTIVE_HELP,
+    long_help: ARG_INTERACTIVE_LONG_HELP,
+  };
 
-pub const OPTION_CONFIG_N: OptionConfig = OptionConfig {
-  brief_description: Some(ARG_NAME_HELP),
-  parse_option_config: ParseOptionConfig {
+  pub const OPTION_CONFIG_N = ARG_NAME_NAME_LONG => {
     id: ARG_NAME_ID,
-    name: ParseOptionName::Both {
-      name_long: ARG_NAME_NAME_LONG,
-      name_short: ARG_NAME_NAME_SHORT,
-    },
-    value_usage: ValueUsage::Required,
-  },
-};
+    short: ARG_NAME_NAME_SHORT,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    required_value,
+    value_name: ARG_NAME_VALUE_NAME,
+    help: ARG_NAME_HELP,
+    long_help: ARG_NAME_LONG_HELP,
+  };
 
-pub const OPTION_CONFIG_Q: OptionConfig = OptionConfig {
-  brief_description: Some(ARG_QUIET_HELP),
-  parse_option_config: ParseOptionConfig {
+  pub const OPTION_CONFIG_Q = ARG_QUIET_NAME_LONG => {
     id: ARG_QUIET_ID,
-    name: ParseOptionName::Both {
-      name_long: ARG_QUIET_NAME_LONG,
-      name_short: ARG_QUIET_NAME_SHORT,
-    },
-    value_usage: ValueUsage::Verboten,
-  },
-};
-
-pub const OPTION_CONFIGS: [OptionConfig; 4] = [
-  OPTION_CONFIG_H,
-  OPTION_CONFIG_I,
-  OPTION_CONFIG_N,
-  OPTION_CONFIG_Q,
-];
+    short: ARG_QUIET_NAME_SHORT,
+    duplicate_policy: DuplicatePolicy::LastWins,
+    help: ARG_QUIET_HELP,
+  };
+}
 
 pub const HELP_INFO: HelpInfo = HelpInfo {
   app_info: &APP_INFO,
   arg_options: &OPTION_CONFIGS,
+  command_name: Some(HELP_INFO_COMMAND_NAME),
+  parse_groups: &[],
+  positionals: &[],
+  sort_options: false,
+  subcommands: &[],
+  usage: None,
+  version_options: false,
 };
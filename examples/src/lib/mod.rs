@@ -5,13 +5,14 @@
 //! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-01-15
-//! - Updated: 2026-04-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 //==============================================================================
 
 use std::io::{Error, Stdin, Write, stdin, stdout};
+use std::process;
 
 use commander::parse::parse_error::ParseError;
 use commander::parse::parse_output::ParseOutput;
@@ -26,6 +27,8 @@ mod test;
 #[derive(Debug)]
 pub struct OptionValues {
   pub errors: Vec<ParseOutput>,
+  /// True if the long help was requested using --help instead of -h
+  pub help_long: bool,
   pub help_wanted: bool,
   pub interactive: Result<bool, ParseError>,
   pub name_option: Option<String>,
@@ -67,7 +70,11 @@ pub fn ask(
 
 pub fn main(option_values: OptionValues) {
   if option_values.help_wanted {
-    HELP_INFO.print();
+    if option_values.help_long {
+      HELP_INFO.print_long();
+    } else {
+      HELP_INFO.print();
+    }
 
     return;
   }
@@ -87,6 +94,8 @@ pub fn main(option_values: OptionValues) {
   if !option_values.unknown.is_empty() {
     print::print_unknown_options(&option_values.unknown);
   }
+
+  process::exit(print::EXIT_CODE_USAGE);
 }
 
 // private functions
@@ -5,7 +5,7 @@
 //! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-01-15
-//! - Updated: 2026-04-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -17,6 +17,7 @@ use super::*;
 fn test_make_greeting_when_name_none() {
   let option_values: OptionValues = OptionValues {
     errors: Vec::new(),
+    help_long: false,
     help_wanted: false,
     interactive: Ok(false),
     name_option: None,
@@ -33,6 +34,7 @@ fn test_make_greeting_when_name_none() {
 fn test_make_greeting_when_name_some() {
   let option_values: OptionValues = OptionValues {
     errors: Vec::new(),
+    help_long: false,
     help_wanted: false,
     interactive: Ok(false),
     name_option: Some(String::from("Test")),
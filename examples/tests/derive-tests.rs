@@ -0,0 +1,179 @@
+//==============================================================================
+//! Integration tests that parse using a struct with the derive macro.
+//!
+//! # Metadata
+//! - Author: [`David Wallace Croft`]
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+//==============================================================================
+
+use ::commander::parse::parse_error::ParseError;
+use ::commander::parse::parse_output::ParseOutput;
+use ::commander::parse::parse_results::ParseResults;
+use ::commander::parse::parse_rule_error::ParseRuleError;
+use ::commander_derive::Commander;
+
+//------------------------------------------------------------------------------
+/// Test options
+//------------------------------------------------------------------------------
+#[derive(Commander, Debug)]
+#[commander(command_name = "test")]
+struct TestOptions {
+  /// Test count
+  #[commander(short = 'c')]
+  count: u32,
+  /// Test help
+  #[commander(short = 'h')]
+  help: bool,
+  /// Test name
+  name: Option<String>,
+  /// Test sizes
+  #[commander(short = 's')]
+  size: Vec<u16>,
+}
+
+fn make_args(args: &[&str]) -> Vec<String> {
+  args.iter().map(|arg: &&str| arg.to_string()).collect()
+}
+
+fn parse(args: &[&str]) -> Result<TestOptions, ParseResults> {
+  TestOptions::parse(&make_args(args))
+}
+
+#[test]
+fn test_parse_0() {
+  let actual: TestOptions = parse(&[
+    "test",
+    "-c=3",
+    "--name=Bob",
+    "-s=1",
+    "--size=2",
+  ])
+  .unwrap();
+
+  assert_eq!(actual.count, 3);
+
+  assert!(!actual.help);
+
+  assert_eq!(actual.name, Some("Bob".to_string()));
+
+  assert_eq!(
+    actual.size,
+    vec![
+      1, 2
+    ]
+  );
+}
+
+#[test]
+fn test_parse_1() {
+  let actual: TestOptions = parse(&[
+    "test",
+    "--count=0",
+  ])
+  .unwrap();
+
+  assert_eq!(actual.name, None);
+
+  assert!(actual.size.is_empty());
+}
+
+#[test]
+fn test_parse_invalid_value_0() {
+  let actual: ParseResults = parse(&[
+    "test", "-c=x", "-s=1",
+  ])
+  .unwrap_err();
+
+  let errors: Vec<ParseOutput> = actual.get_errors();
+
+  assert_eq!(errors.len(), 1);
+
+  assert_eq!(errors[0].known.as_deref(), Some("count"));
+
+  assert_eq!(errors[0].error, Some(ParseError::InvalidValue));
+}
+
+#[test]
+fn test_parse_invalid_value_1() {
+  let actual: ParseResults = parse(&[
+    "test", "-c=1", "-s=1", "-s=-1",
+  ])
+  .unwrap_err();
+
+  let errors: Vec<ParseOutput> = actual.get_errors();
+
+  assert_eq!(errors.len(), 1);
+
+  assert_eq!(errors[0].known.as_deref(), Some("size"));
+
+  assert_eq!(errors[0].error, Some(ParseError::InvalidValue));
+}
+
+#[test]
+fn test_parse_missing_required_0() {
+  let actual: ParseResults = parse(&[
+    "test",
+    "--name=Bob",
+  ])
+  .unwrap_err();
+
+  assert_eq!(
+    actual.rule_errors,
+    vec![
+      ParseRuleError::MissingOptions {
+        ids: vec!["count".to_string()],
+        names: vec!["--count".to_string()],
+      }
+    ]
+  );
+}
+
+#[test]
+fn test_parse_help_0() {
+  let actual: ParseResults = parse(&[
+    "test", "--help",
+  ])
+  .unwrap_err();
+
+  assert!(actual.is_present("help"));
+}
+
+#[test]
+fn test_parse_help_1() {
+  let actual: TestOptions = parse(&[
+    "test",
+    "-h",
+    "-c=1",
+    "--unknown",
+  ])
+  .unwrap();
+
+  assert!(actual.help);
+}
+
+#[test]
+fn test_make_parse_results_version_0() {
+  let actual: ParseResults = TestOptions::make_parse_results(&make_args(&[
+    "test", "-V",
+  ]));
+
+  assert!(actual.is_present("version"));
+}
+
+#[test]
+fn test_version_0() {
+  assert_eq!(
+    TestOptions::APP_INFO.version,
+    Some(env!("CARGO_PKG_VERSION"))
+  );
+
+  assert_eq!(
+    TestOptions::BUILD_INFO.version,
+    Some(env!("CARGO_PKG_VERSION"))
+  );
+}
@@ -5,7 +5,7 @@
 //! - Author: [`David Wallace Croft`]
 //! - Copyright: &copy; 2024-2026 [`CroftSoft Inc`]
 //! - Created: 2024-04-06
-//! - Updated: 2026-04-19
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -39,29 +39,90 @@ fn test_output_no_args() {
 
 #[test]
 fn test_output_args_help() {
-  make_command().args(["--help"]).assert().success().stdout(
-    r#"
-CroftSoft Commander Example
+  make_command()
+    .args(["--help"])
+    .env("COLUMNS", "80")
+    .assert()
+    .success()
+    .stdout(
+      r#"
+CroftSoft Commander Example 0.2.0-SNAPSHOT
 Copyright © 2022-2024 CroftSoft Inc
 David Wallace Croft <david@CroftSoft.com>
 Command-line arguments parser example
 
+Greets the user by name, asking for the name first unless interactive mode is
+turned off.
+
+USAGE:
+  example-commander [-hqV] [-i[=BOOL]] [-n=NAME] [--verbose]
+
 OPTIONS:
-  -h, --help         Show command-line options
-  -i, --interactive  true/false, defaults to true
-  -n, --name         Any value not starting with a hyphen (-)
-  -q, --quiet        Suppress the exclamation mark
+  -h, --help
+        Show command-line options
+
+  -i, --interactive[=<BOOL>]
+        true/false, defaults to true
+
+        Asks for the name before the greeting, showing the name from --name as
+        the default.
+
+        Example: example-commander -i=false
+
+  -n, --name=<NAME>
+        Any value not starting with a hyphen (-)
+
+        The name to greet, defaulting to World.
+
+  -q, --quiet
+        Suppress the exclamation mark
+
+  -V, --version
+        Show version information
+
+  --verbose
+        Show the build information with --version
 "#,
-  );
+    );
 }
 
 #[test]
-fn test_output_args_help_verboten() {
+fn test_output_args_help_short() {
   make_command()
-    .args(["--help=true"])
+    .args(["-h"])
+    .env("COLUMNS", "80")
     .assert()
     .success()
     .stdout(
+      r#"
+CroftSoft Commander Example 0.2.0-SNAPSHOT
+Copyright © 2022-2024 CroftSoft Inc
+David Wallace Croft <david@CroftSoft.com>
+Command-line arguments parser example
+
+USAGE:
+  example-commander [-hqV] [-i[=BOOL]] [-n=NAME] [--verbose]
+
+OPTIONS:
+  -h, --help                  Show command-line options
+  -i, --interactive[=<BOOL>]  true/false, defaults to true
+  -n, --name=<NAME>           Any value not starting with a hyphen (-)
+  -q, --quiet                 Suppress the exclamation mark
+  -V, --version               Show version information
+  --verbose                   Show the build information with --version
+"#,
+    );
+}
+
+#[test]
+fn test_output_args_help_verboten() {
+  make_command()
+    .args(["--help=true"])
+    .assert()
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr(
       "Verboten value present for option at argument index 1: \"help\"\n",
     );
 }
@@ -71,8 +132,10 @@ fn test_output_args_interactive_invalid() {
   make_command()
     .args(["-i=invalid"])
     .assert()
-    .success()
-    .stdout(
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr(
       "Invalid value for option at argument index 1 character index 0: 'i'\n",
     );
 }
@@ -88,9 +151,15 @@ fn test_output_args_name_long() {
 
 #[test]
 fn test_output_args_name_required_value_missing() {
-  make_command().args(["--name"]).assert().success().stdout(
-    "Required value missing for option at argument index 1: \"name\"\n",
-  );
+  make_command()
+    .args(["--name"])
+    .assert()
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr(
+      "Required value missing for option at argument index 1: \"name\"\n",
+    );
 }
 
 #[test]
@@ -104,9 +173,15 @@ fn test_output_args_name_short() {
 
 #[test]
 fn test_output_args_name_value_missing_after_equals() {
-  make_command().args(["--name="]).assert().success().stdout(
-    "Value missing after equals for option at argument index 1: \"name\"\n",
-  );
+  make_command()
+    .args(["--name="])
+    .assert()
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr(
+      "Value missing after equals for option at argument index 1: \"name\"\n",
+    );
 }
 
 #[test]
@@ -159,15 +234,46 @@ fn test_output_args_non_interactive_name_predicate() {
     .stdout(predicate::str::contains("David"));
 }
 
+#[test]
+fn test_output_args_version() {
+  make_command()
+    .args(["--version"])
+    .assert()
+    .success()
+    .stdout("CroftSoft Commander Example 0.2.0-SNAPSHOT\n");
+}
+
+#[test]
+fn test_output_args_version_short() {
+  make_command()
+    .args(["-V"])
+    .assert()
+    .success()
+    .stdout("CroftSoft Commander Example 0.2.0-SNAPSHOT\n");
+}
+
+#[test]
+fn test_output_args_version_verbose() {
+  make_command()
+    .args([
+      "--version",
+      "--verbose",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("license: MIT\n"))
+    .stdout(predicate::str::contains("crate version: 0.2.0-SNAPSHOT\n"));
+}
+
 #[test]
 fn test_output_args_unknown_long() {
   make_command()
     .args(["--unknown"])
     .assert()
-    // TODO: Should this be failure?
-    .success()
-    // TODO: Should this go to standard error?
-    .stdout("Unknown option at argument index 1: \"unknown\"\n");
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr("Unknown option at argument index 1: \"unknown\"\n");
 }
 
 #[test]
@@ -175,10 +281,23 @@ fn test_output_args_unknown_short() {
   make_command()
     .args(["-u"])
     .assert()
-    // TODO: Should this be failure?
-    .success()
-    // TODO: Should this go to standard error?
-    .stdout("Unknown option at argument index 1 character index 0: 'u'\n");
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr("Unknown option at argument index 1 character index 0: 'u'\n");
+}
+
+#[test]
+fn test_output_args_unknown_suggestion() {
+  make_command()
+    .args(["--nmae=David"])
+    .assert()
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr(
+      "Unknown option at argument index 1: \"nmae\", did you mean --name?\n",
+    );
 }
 
 #[test]
@@ -186,8 +305,8 @@ fn test_output_args_unknown_option_value() {
   make_command()
     .args(["--unknown=true"])
     .assert()
-    // TODO: Should this be failure?
-    .success()
-    // TODO: Should this go to standard error?
-    .stdout("Unknown option at argument index 1: \"unknown\"\n");
+    .failure()
+    .code(2)
+    .stdout("")
+    .stderr("Unknown option at argument index 1: \"unknown\"\n");
 }